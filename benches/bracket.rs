@@ -0,0 +1,88 @@
+//! Times building and solving brackets of increasing size. Run with `cargo bench`.
+//!
+//! Both should scale linearly with the number of entrants, so the time per entrant should stay roughly the same from one size to the next.
+use rand::RngCore;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use ultra_tournament::*;
+
+const SIZES: [usize; 4] = [10_000, 100_000, 1_000_000, 2_000_000];
+
+#[derive(Debug, Clone, Copy)]
+struct IntFighter(u32);
+impl fmt::Display for IntFighter {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Int Fighter: {}", self.0)
+	}
+}
+
+#[derive(Clone, Default)]
+struct IntBattleSystem;
+impl BattleSystem<IntFighter, u32> for IntBattleSystem {
+	fn battle(
+		&mut self,
+		a: Arc<RwLock<IntFighter>>,
+		b: Arc<RwLock<IntFighter>>,
+		_: &mut dyn RngCore,
+	) -> BattleResult<u32> {
+		let (a, b) = (a.read().unwrap().0, b.read().unwrap().0);
+		if a >= b {
+			BattleResult::Solved(TournamentRoundResult::A, a - b)
+		} else {
+			BattleResult::Solved(TournamentRoundResult::B, b - a)
+		}
+	}
+	fn tiebreaker(
+		&mut self,
+		_: Arc<RwLock<IntFighter>>,
+		_: Arc<RwLock<IntFighter>>,
+		_: &mut dyn RngCore,
+	) -> TiebreakerResult<u32> {
+		Ok((TournamentRoundResult::A, 0))
+	}
+}
+
+type IntTournament = Tournament<IntFighter, u32, IntBattleSystem>;
+
+fn entrants(size: usize) -> Vec<IntFighter> {
+	// A cheap, deterministic shuffle of the fighters' strengths.
+	(0..size as u32)
+		.map(|i| IntFighter(i.wrapping_mul(2_654_435_761)))
+		.collect()
+}
+
+fn report(name: &str, size: usize, elapsed: Duration) {
+	println!(
+		"{:<24} {:>9} entrants {:>10.2} ms {:>8.1} ns/entrant",
+		name,
+		size,
+		elapsed.as_secs_f64() * 1e3,
+		elapsed.as_nanos() as f64 / size as f64
+	);
+}
+
+fn main() {
+	for size in SIZES.iter().copied() {
+		let start = Instant::now();
+		let mut t = IntTournament::new(entrants(size)).unwrap();
+		report("new (ordered)", size, start.elapsed());
+
+		let start = Instant::now();
+		t.solve().unwrap();
+		report("solve", size, start.elapsed());
+
+		let start = Instant::now();
+		IntTournament::new_seeded(entrants(size), Seeding::Standard).unwrap();
+		report("new (standard seeding)", size, start.elapsed());
+
+		let start = Instant::now();
+		DoubleEliminationTournament::<IntFighter, u32, IntBattleSystem>::new(
+			entrants(size),
+			true,
+		)
+		.unwrap();
+		report("new (double elimination)", size, start.elapsed());
+		println!();
+	}
+}
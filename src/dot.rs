@@ -0,0 +1,199 @@
+use crate::double_elimination::*;
+use crate::tournament::*;
+use crate::types::*;
+use petgraph::{graph::NodeIndex, Graph};
+use std::fmt::{Debug, Display};
+use std::sync::{Arc, RwLock};
+
+/// Colour of the rounds and edges on the champion's winning path.
+const WINNING_COLOR: &str = "goldenrod";
+
+impl<
+		E: Debug + Display + Clone,
+		M: Debug + Display + Clone + Default,
+		B: BattleSystem<E, M>,
+	> Tournament<E, M, B>
+{
+	/// Export the bracket as a [Graphviz](https://graphviz.org/) DOT graph, laid out from the entrants on the left to the grand finals on the right.
+	///
+	/// - Entrant nodes are labelled with the entrant's `Display` text.
+	/// - Completed rounds are labelled with their winner and metadata, or as a walkover. Incomplete rounds are dashed and greyed out.
+	/// - Edges are labelled with the side, `A` or `B`, the child fills in the round above it.
+	/// - Once the grand finals have been solved, every round won by the champion, and the edges between them, are highlighted.
+	///
+	/// # Example
+	/// ```
+	/// # use ultra_tournament::*;
+	/// # use rand::RngCore;
+	/// # use std::sync::{Arc, RwLock};
+	/// # #[derive(Debug, Clone, Default)]
+	/// # struct MyMetadata;
+	/// # impl MyMetadata {
+	/// # 	fn new() -> Self {
+	/// # 		Self
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Display for MyMetadata {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "My Metadata")
+	/// # 	}
+	/// # }
+	/// # #[derive(Clone, Default)]
+	/// # struct MyBattleSystem;
+	/// # impl BattleSystem<u32, MyMetadata> for MyBattleSystem {
+	/// # 	fn battle(&mut self, a: Arc<RwLock<u32>>, b: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> BattleResult<MyMetadata> {
+	/// # 		if *a.read().unwrap() >= *b.read().unwrap() {
+	/// # 			BattleResult::Solved(TournamentRoundResult::A, MyMetadata::new())
+	/// # 		} else {
+	/// # 			BattleResult::Solved(TournamentRoundResult::B, MyMetadata::new())
+	/// # 		}
+	/// # 	}
+	/// # 	fn tiebreaker(&mut self, _: Arc<RwLock<u32>>, _: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> TiebreakerResult<MyMetadata> {
+	/// # 		Ok((TournamentRoundResult::A, MyMetadata::new()))
+	/// # 	}
+	/// # }
+	/// # fn main() {
+	/// let mut t =
+	/// 	Tournament::<u32, MyMetadata, MyBattleSystem>::new(vec![1, 2, 3, 4]).unwrap();
+	/// t.solve().unwrap();
+	///
+	/// let dot = t.to_dot().unwrap();
+	/// assert!(dot.starts_with("digraph tournament {"));
+	/// # }
+	/// ```
+	pub fn to_dot(&self) -> Result<String> {
+		let champion = self.winner(*self.grand_finals())?;
+		bracket_dot::<E, M, B>(self.graph(), |eid| self.entrant(eid), champion)
+	}
+}
+
+impl<
+		E: Debug + Display + Clone,
+		M: Debug + Display + Clone + Default,
+		B: BattleSystem<E, M>,
+	> DoubleEliminationTournament<E, M, B>
+{
+	/// Export both brackets as a [Graphviz](https://graphviz.org/) DOT graph, as per [`Tournament::to_dot()`](struct.Tournament.html#method.to_dot). Loser nodes are drawn with dashed edges to the round they were eliminated from, and the champion's rounds are highlighted once the [champion](#method.champion) has been decided.
+	pub fn to_dot(&self) -> Result<String> {
+		bracket_dot::<E, M, B>(
+			self.graph(),
+			|eid| self.entrant(eid),
+			self.champion()?,
+		)
+	}
+}
+
+/// Writes `graph` as a DOT graph, highlighting every round won by `champion`.
+fn bracket_dot<
+	E: Debug + Display + Clone,
+	M: Debug + Display + Clone + Default,
+	B: BattleSystem<E, M>,
+>(
+	graph: &Graph<TournamentNode<M>, TournamentEdge>,
+	entrant: impl Fn(EntrantId) -> Arc<RwLock<E>>,
+	champion: Option<EntrantId>,
+) -> Result<String> {
+	let name = |eid: EntrantId| -> Result<String> {
+		let arc = entrant(eid);
+		let e = arc
+			.read()
+			.or(Err(TournamentError::EntrantLockPoisoned(eid)))?;
+		Ok(escape(&e.to_string()))
+	};
+	let on_path = |id: NodeIndex| -> Result<bool> {
+		Ok(match graph[id] {
+			TournamentNode::Loser => false,
+			_ => {
+				champion.is_some()
+					&& Tournament::<E, M, B>::_winner(graph, id)? == champion
+			}
+		})
+	};
+
+	let mut lines = vec![
+		"digraph tournament {".to_string(),
+		"\trankdir=RL;".to_string(),
+		"\tnode [shape=box];".to_string(),
+	];
+	for id in graph.node_indices() {
+		let mut attributes = match &graph[id] {
+			TournamentNode::Entrant(eid) => format!("label=\"{}\"", name(*eid)?),
+			TournamentNode::Round(TournamentRound::Incomplete) => format!(
+				"label=\"Round {}\\nIncomplete\", style=\"rounded,dashed\", color=gray50, fontcolor=gray50",
+				id.index()
+			),
+			TournamentNode::Round(TournamentRound::Complete {
+				metadata,
+				decision,
+				..
+			}) => {
+				let winner = Tournament::<E, M, B>::_winner(graph, id)?
+					.ok_or(TournamentError::MalformedRound(id))?;
+				format!(
+					"label=\"Round {}\\nWinner: {}\\n{}\", style=rounded",
+					id.index(),
+					name(winner)?,
+					match decision {
+						RoundDecision::Played => escape(&metadata.to_string()),
+						RoundDecision::Walkover => "Walkover".to_string(),
+					}
+				)
+			}
+			TournamentNode::Loser => {
+				match Tournament::<E, M, B>::_winner(graph, id)? {
+					Some(loser) => format!(
+						"label=\"Loser\\n{}\", shape=ellipse",
+						name(loser)?
+					),
+					None => "label=\"Loser\", shape=ellipse, style=dashed".to_string(),
+				}
+			}
+		};
+		if on_path(id)? {
+			attributes.push_str(&format!(", color={}, penwidth=2", WINNING_COLOR));
+		}
+		lines.push(format!("\tn{} [{}];", id.index(), attributes));
+	}
+	for id in graph.node_indices() {
+		match graph[id] {
+			TournamentNode::Round(_) => {
+				let (a, b) = Tournament::<E, M, B>::_child_nodes(graph, id)?;
+				for (child, side) in [(a, "A"), (b, "B")].iter() {
+					let highlight = if on_path(id)? && on_path(*child)? {
+						format!(", color={}, penwidth=2", WINNING_COLOR)
+					} else {
+						String::new()
+					};
+					lines.push(format!(
+						"\tn{} -> n{} [label=\"{}\"{}];",
+						id.index(),
+						child.index(),
+						side,
+						highlight
+					));
+				}
+			}
+			TournamentNode::Loser => {
+				for round in graph.neighbors_directed(id, petgraph::Direction::Outgoing)
+				{
+					lines.push(format!(
+						"\tn{} -> n{} [label=\"Loser\", style=dashed];",
+						id.index(),
+						round.index()
+					));
+				}
+			}
+			TournamentNode::Entrant(_) => {}
+		}
+	}
+	lines.push("}".to_string());
+	Ok(lines.join("\n") + "\n")
+}
+
+/// Escapes text for use inside a quoted DOT string. Line breaks become DOT's centred `\n`.
+fn escape(text: &str) -> String {
+	text
+		.replace('\\', "\\\\")
+		.replace('"', "\\\"")
+		.replace('\n', "\\n")
+}
@@ -0,0 +1,573 @@
+use crate::observer::*;
+#[cfg(feature = "serde")]
+use crate::serialization::*;
+use crate::tournament::{walkover, Snapshots, Tournament};
+use crate::types::*;
+#[doc(hidden)]
+use petgraph::prelude::*;
+#[doc(no_inline)]
+use petgraph::{graph::NodeIndex, Graph};
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::clone::Clone;
+use std::default::Default;
+use std::fmt::{Debug, Display};
+use std::sync::{Arc, RwLock};
+
+/// `DoubleEliminationTournament<E, M, B>` creates a double-elimination tournament bracket, where entrants are only knocked out after losing twice.
+///
+/// The winners bracket is laid out exactly like a [`Tournament`](struct.Tournament.html). The loser of every round in the winners bracket drops into a slot of the losers bracket, represented in the [graph](#method.graph) by a [`TournamentNode::Loser`](enum.TournamentNode.html#variant.Loser) node linking back to that round.
+///
+/// The champion of the winners bracket and the champion of the losers bracket meet in the [grand finals](#method.grand_finals). If the tournament was created with a bracket reset and the losers bracket champion wins the grand finals, the two play once more in the [bracket reset](#method.bracket_reset) round.
+///
+/// Type parameters are identical to those of [`Tournament`](struct.Tournament.html).
+#[derive(Debug)]
+pub struct DoubleEliminationTournament<
+	E: Debug + Display + Clone,
+	M: Debug + Display + Clone + Default,
+	B: BattleSystem<E, M>,
+> {
+	graph: Graph<TournamentNode<M>, TournamentEdge>,
+	entrants: Vec<Arc<RwLock<E>>>,
+	winners_finals: NodeIndex,
+	losers_finals: NodeIndex,
+	grand_finals: NodeIndex,
+	bracket_reset: Option<NodeIndex>,
+	battle_system: B,
+	rng: StdRng,
+	snapshots: Option<Snapshots<E>>,
+	observers: Observers<M>,
+	withdrawn: Vec<EntrantId>,
+	phantom_metadata: std::marker::PhantomData<M>,
+}
+
+impl<
+		E: Debug + Display + Clone,
+		M: Debug + Display + Clone + Default,
+		B: BattleSystem<E, M> + Default,
+	> DoubleEliminationTournament<E, M, B>
+{
+	/// Create a new `DoubleEliminationTournament` from a `Vec<E>` of entrant structs. The winners bracket is assigned in the `Vec<E>`'s order, as per [`Tournament::new()`](struct.Tournament.html#method.new).
+	///
+	/// When `bracket_reset` is `true`, a final round is added that is only played if the champion of the losers bracket wins the grand finals.
+	///
+	/// Requires at least two entrants.
+	pub fn new(entrants: Vec<E>, bracket_reset: bool) -> Result<Self> {
+		Self::new_with_battle_system(entrants, bracket_reset, B::default())
+	}
+}
+
+impl<
+		E: Debug + Display + Clone,
+		M: Debug + Display + Clone + Default,
+		B: BattleSystem<E, M>,
+	> DoubleEliminationTournament<E, M, B>
+{
+	/// Create a new `DoubleEliminationTournament` as per [`new()`](#method.new), where rounds are solved with the supplied instance of the battle system.
+	pub fn new_with_battle_system(
+		entrants: Vec<E>,
+		bracket_reset: bool,
+		battle_system: B,
+	) -> Result<Self> {
+		if entrants.len() < 2 {
+			return Err(TournamentError::NeedsAtLeastTwoEntrants);
+		}
+
+		let entrant_arcs: Vec<Arc<RwLock<E>>> = entrants
+			.into_iter()
+			.map(|entrant| Arc::new(RwLock::new(entrant)))
+			.collect();
+		let entrant_ids: Vec<EntrantId> =
+			(0..entrant_arcs.len()).map(EntrantId).collect();
+
+		let mut graph: Graph<TournamentNode<M>, TournamentEdge> = Graph::new();
+		let winners_finals = graph.add_node(Self::incomplete());
+		Tournament::<E, M, B>::add_layer(&mut graph, winners_finals, &entrant_ids);
+
+		// Winners bracket rounds, grouped by distance from the winners finals.
+		let mut levels: Vec<Vec<NodeIndex>> = vec![];
+		Self::collect_levels(&graph, winners_finals, 0, &mut levels);
+
+		let mut pool: Vec<NodeIndex> = vec![];
+		for (i, level) in levels.iter().rev().enumerate() {
+			let mut drops: Vec<NodeIndex> = level
+				.iter()
+				.map(|round| {
+					let loser = graph.add_node(TournamentNode::Loser);
+					graph.add_edge(loser, *round, TournamentEdge::A);
+					loser
+				})
+				.collect();
+			if pool.is_empty() {
+				pool = drops;
+				continue;
+			}
+			// Flip the order of every other wave of dropped entrants, to push
+			// rematches from the winners bracket as late as possible.
+			if i % 2 == 0 {
+				drops.reverse();
+			}
+			while pool.len() > drops.len() {
+				pool = Self::halve(&mut graph, pool);
+			}
+			let mut merged = vec![];
+			let mut drops = drops.into_iter();
+			for slot in pool {
+				let drop = drops.next().ok_or(TournamentError::MalformedBracket)?;
+				merged.push(Self::add_round(&mut graph, slot, drop));
+			}
+			merged.extend(drops);
+			pool = merged;
+		}
+		while pool.len() > 1 {
+			pool = Self::halve(&mut graph, pool);
+		}
+		let losers_finals = pool.pop().ok_or(TournamentError::MalformedBracket)?;
+
+		let grand_finals =
+			Self::add_round(&mut graph, winners_finals, losers_finals);
+		let bracket_reset = if bracket_reset {
+			let loser = graph.add_node(TournamentNode::Loser);
+			graph.add_edge(loser, grand_finals, TournamentEdge::A);
+			Some(Self::add_round(&mut graph, loser, grand_finals))
+		} else {
+			None
+		};
+
+		Ok(DoubleEliminationTournament::<E, M, B> {
+			graph,
+			entrants: entrant_arcs,
+			winners_finals,
+			losers_finals,
+			grand_finals,
+			bracket_reset,
+			battle_system,
+			rng: StdRng::from_entropy(),
+			snapshots: None,
+			observers: Observers::new(),
+			withdrawn: vec![],
+			phantom_metadata: std::marker::PhantomData,
+		})
+	}
+
+	fn incomplete() -> TournamentNode<M> {
+		TournamentNode::Round(TournamentRound::Incomplete)
+	}
+
+	fn add_round(
+		graph: &mut Graph<TournamentNode<M>, TournamentEdge>,
+		a: NodeIndex,
+		b: NodeIndex,
+	) -> NodeIndex {
+		let round = graph.add_node(Self::incomplete());
+		graph.add_edge(round, a, TournamentEdge::A);
+		graph.add_edge(round, b, TournamentEdge::B);
+		round
+	}
+
+	/// Pair up neighbouring slots of the losers bracket. An odd slot out is carried over to the next wave.
+	fn halve(
+		graph: &mut Graph<TournamentNode<M>, TournamentEdge>,
+		pool: Vec<NodeIndex>,
+	) -> Vec<NodeIndex> {
+		pool
+			.chunks(2)
+			.map(|pair| match pair {
+				[a, b] => Self::add_round(graph, *a, *b),
+				_ => pair[0],
+			})
+			.collect()
+	}
+
+	fn collect_levels(
+		graph: &Graph<TournamentNode<M>, TournamentEdge>,
+		id: NodeIndex,
+		depth: usize,
+		levels: &mut Vec<Vec<NodeIndex>>,
+	) {
+		if let Some(TournamentNode::Round(_)) = graph.node_weight(id) {
+			if levels.len() <= depth {
+				levels.push(vec![]);
+			}
+			levels[depth].push(id);
+			if let Ok((a, b)) = Tournament::<E, M, B>::_child_nodes(graph, id) {
+				Self::collect_levels(graph, a, depth + 1, levels);
+				Self::collect_levels(graph, b, depth + 1, levels);
+			}
+		}
+	}
+
+	/// Get the number of entrants in the tournament.
+	pub fn len_entrants(&self) -> usize {
+		self.entrants.len()
+	}
+
+	/// Get the number of rounds in the tournament, complete and incomplete. Includes the bracket reset round, if there is one.
+	pub fn len_rounds(&self) -> usize {
+		self
+			.graph
+			.raw_nodes()
+			.iter()
+			.filter(|node| node.weight.round().is_some())
+			.count()
+	}
+
+	/// Get an `Arc<RwLock<E>>` encapsulating an entrant of specified [`EntrantId`](struct.EntrantId.html)
+	pub fn entrant(&self, id: EntrantId) -> Arc<RwLock<E>> {
+		self.entrants.get(id.0).unwrap().clone()
+	}
+
+	/// Get a ref to the [`NodeIndex`](https://docs.rs/petgraph/0.5.1/petgraph/graph/struct.NodeIndex.html) of the final round of the winners bracket.
+	pub fn winners_finals(&self) -> &NodeIndex {
+		&self.winners_finals
+	}
+
+	/// Get a ref to the [`NodeIndex`](https://docs.rs/petgraph/0.5.1/petgraph/graph/struct.NodeIndex.html) of the final round of the losers bracket. With only two entrants, this is a [`TournamentNode::Loser`](enum.TournamentNode.html#variant.Loser) linking to the winners finals.
+	pub fn losers_finals(&self) -> &NodeIndex {
+		&self.losers_finals
+	}
+
+	/// Get a ref to the [`NodeIndex`](https://docs.rs/petgraph/0.5.1/petgraph/graph/struct.NodeIndex.html) of the grand finals, played between the champions of the winners and losers brackets.
+	pub fn grand_finals(&self) -> &NodeIndex {
+		&self.grand_finals
+	}
+
+	/// Get a ref to the [`NodeIndex`](https://docs.rs/petgraph/0.5.1/petgraph/graph/struct.NodeIndex.html) of the bracket reset round, if the tournament was created with one.
+	pub fn bracket_reset(&self) -> Option<&NodeIndex> {
+		self.bracket_reset.as_ref()
+	}
+
+	/// Get a ref to the internal [`Graph`](https://docs.rs/petgraph/0.5.1/petgraph/graph/struct.Graph.html) used by the tournament.
+	pub fn graph(&self) -> &Graph<TournamentNode<M>, TournamentEdge> {
+		&self.graph
+	}
+
+	/// Get a ref to the battle system used to solve rounds.
+	pub fn battle_system(&self) -> &B {
+		&self.battle_system
+	}
+
+	/// Get a mutable ref to the battle system used to solve rounds, to change its configuration between rounds.
+	pub fn battle_system_mut(&mut self) -> &mut B {
+		&mut self.battle_system
+	}
+
+	/// Seed the random number generator passed to the battle system, so that solving the tournament is reproducible. By default, it's seeded from system entropy.
+	pub fn set_seed(&mut self, seed: u64) {
+		self.rng = StdRng::seed_from_u64(seed);
+	}
+
+	/// Replace the random number generator passed to the battle system.
+	pub fn set_rng(&mut self, rng: StdRng) {
+		self.rng = rng;
+	}
+
+	/// Enable or disable entrant snapshots, as per [`Tournament::set_snapshots()`](struct.Tournament.html#method.set_snapshots).
+	pub fn set_snapshots(&mut self, enabled: bool) {
+		self.snapshots = if enabled {
+			self.snapshots.take().or_else(|| Some(vec![]))
+		} else {
+			None
+		};
+	}
+
+	/// Whether entrant snapshots are enabled.
+	pub fn snapshots_enabled(&self) -> bool {
+		self.snapshots.is_some()
+	}
+
+	/// Register a [`TournamentObserver`](trait.TournamentObserver.html) to receive events while the tournament is solved, as per [`Tournament::add_observer()`](struct.Tournament.html#method.add_observer). [`on_tournament_complete()`](trait.TournamentObserver.html#method.on_tournament_complete) is sent once the [champion](#method.champion) is decided.
+	pub fn add_observer(
+		&mut self,
+		observer: impl TournamentObserver<M> + Send + 'static,
+	) {
+		self.observers.add(observer);
+	}
+
+	/// Remove every registered [`TournamentObserver`](trait.TournamentObserver.html).
+	pub fn clear_observers(&mut self) {
+		self.observers.clear();
+	}
+
+	/// Notifies observers if completing round `id` decided the champion.
+	fn notify_if_complete(&mut self, id: NodeIndex) -> Result<()> {
+		if id == self.grand_finals || Some(id) == self.bracket_reset {
+			if let Some(champion) = self.champion()? {
+				self.observers.tournament_complete(champion);
+			}
+		}
+		Ok(())
+	}
+
+	/// Get the [`EntrantId`](struct.EntrantId.html) of the solved winner of a particular node. Returns `None` if the round hasn't been calculated yet.
+	pub fn winner(&self, id: NodeIndex) -> Result<Option<EntrantId>> {
+		Tournament::<E, M, B>::_winner(&self.graph, id)
+	}
+
+	/// Get the [`EntrantId`](struct.EntrantId.html) of the solved loser of a particular round. Returns `None` if the round hasn't been calculated yet.
+	pub fn loser(&self, id: NodeIndex) -> Result<Option<EntrantId>> {
+		Tournament::<E, M, B>::_loser(&self.graph, id)
+	}
+
+	/// Get the [`EntrantId`](struct.EntrantId.html) of the tournament's champion. Returns `None` until the tournament has been decided, including the bracket reset if one has to be played.
+	pub fn champion(&self) -> Result<Option<EntrantId>> {
+		let grand_finals_winner = match self.winner(self.grand_finals)? {
+			Some(eid) => eid,
+			None => return Ok(None),
+		};
+		match self.bracket_reset {
+			Some(reset) if self.needs_bracket_reset()? => self.winner(reset),
+			_ => Ok(Some(grand_finals_winner)),
+		}
+	}
+
+	fn needs_bracket_reset(&self) -> Result<bool> {
+		Ok(
+			self.bracket_reset.is_some()
+				&& self.winner(self.grand_finals)?.is_some()
+				&& self.winner(self.grand_finals)?
+					== self.winner(self.losers_finals)?,
+		)
+	}
+
+	/// Records the result of a round that was played outside of the battle system, such as by humans, and returns its winner. Errors are as per [`Tournament::report_result()`](struct.Tournament.html#method.report_result).
+	pub fn report_result(
+		&mut self,
+		id: NodeIndex,
+		result: TournamentRoundResult,
+		metadata: M,
+	) -> Result<EntrantId> {
+		let winner = Tournament::<E, M, B>::_report_result(
+			&mut self.graph,
+			id,
+			result,
+			metadata,
+		)?;
+		if let Some(metadata) = self.graph[id].metadata() {
+			self.observers.round_complete(id, result, metadata);
+		}
+		self.notify_if_complete(id)?;
+		Ok(winner)
+	}
+
+	/// Resets a completed round back to [`TournamentRound::Incomplete`](enum.TournamentRound.html#variant.Incomplete), along with every later round that depended on its result in either bracket, as per [`Tournament::revert_round()`](struct.Tournament.html#method.revert_round).
+	pub fn revert_round(&mut self, id: NodeIndex) -> Result<Vec<NodeIndex>> {
+		let reverted = Tournament::<E, M, B>::_revert_round(&mut self.graph, id)?;
+		Tournament::<E, M, B>::_restore(
+			&mut self.snapshots,
+			&self.entrants,
+			&reverted,
+		)?;
+		Ok(reverted)
+	}
+
+	/// Withdraw an entrant from the tournament, as per [`Tournament::withdraw()`](struct.Tournament.html#method.withdraw). Every round they reach in either bracket is decided by walkover, so a withdrawn entrant drops straight through the losers bracket.
+	pub fn withdraw(&mut self, id: EntrantId) -> Result<()> {
+		if id.0 >= self.entrants.len() {
+			return Err(TournamentError::EntrantNotFound(id));
+		}
+		if !self.withdrawn.contains(&id) {
+			self.withdrawn.push(id);
+		}
+		Ok(())
+	}
+
+	/// Whether an entrant has [withdrawn](#method.withdraw).
+	pub fn is_withdrawn(&self, id: EntrantId) -> bool {
+		self.withdrawn.contains(&id)
+	}
+
+	/// Get every entrant that has [withdrawn](#method.withdraw), in the order they withdrew.
+	pub fn withdrawn(&self) -> &Vec<EntrantId> {
+		&self.withdrawn
+	}
+
+	/// Solves all rounds in the tournament, up to and including the grand finals. The bracket reset is only solved if the champion of the losers bracket wins the grand finals.
+	pub fn solve(&mut self) -> Result<()> {
+		self.solve_round(self.grand_finals)?;
+		if let Some(reset) = self.bracket_reset {
+			if self.needs_bracket_reset()? {
+				self.solve_round(reset)?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Solves rounds only up to the specified round, returning its winner. A failing battle system leaves the tournament consistent, as per [`Tournament::solve_round()`](struct.Tournament.html#method.solve_round).
+	pub fn solve_round(&mut self, id: NodeIndex) -> Result<EntrantId> {
+		use TournamentError::*;
+		use TournamentNode::*;
+
+		match self.graph.node_weight(id).ok_or(RoundNotFound(id))?.clone() {
+			Entrant(eid) => Ok(eid),
+			Loser => {
+				let round = self
+					.graph
+					.neighbors_directed(id, Direction::Outgoing)
+					.next()
+					.ok_or(MalformedRound(id))?;
+				self.solve_round(round)?;
+				self.loser(round)?.ok_or(MalformedRound(round))
+			}
+			Round(TournamentRound::Complete { .. }) => {
+				self.winner(id)?.ok_or(MalformedRound(id))
+			}
+			Round(TournamentRound::Incomplete) => {
+				let (a, b) = Tournament::<E, M, B>::_child_nodes(&self.graph, id)?;
+				let ent_a = self.solve_round(a)?;
+				let ent_b = self.solve_round(b)?;
+				if let Some(result) = walkover(&self.withdrawn, ent_a, ent_b) {
+					Tournament::<E, M, B>::_walkover(
+						&mut self.graph,
+						&mut self.observers,
+						id,
+						result,
+					)?;
+					self.notify_if_complete(id)?;
+					return Ok(match result {
+						TournamentRoundResult::A => ent_a,
+						TournamentRoundResult::B => ent_b,
+					});
+				}
+				let arc_a = self
+					.entrants
+					.get(ent_a.0)
+					.ok_or(EntrantNotFound(ent_a))?
+					.clone();
+				let arc_b = self
+					.entrants
+					.get(ent_b.0)
+					.ok_or(EntrantNotFound(ent_b))?
+					.clone();
+				self.observers.round_start(id, ent_a, ent_b);
+				Tournament::<E, M, B>::_snapshot(
+					&mut self.snapshots,
+					&self.entrants,
+					id,
+					&[ent_a, ent_b],
+				)?;
+
+				let outcome = match self.battle_system.battle(
+					arc_a.clone(),
+					arc_b.clone(),
+					&mut self.rng,
+				) {
+					BattleResult::Solved(round_result, metadata) => {
+						Ok((round_result, metadata))
+					}
+					BattleResult::Tie => {
+						self.observers.tie(id);
+						self.battle_system.tiebreaker(arc_a, arc_b, &mut self.rng)
+					}
+					BattleResult::Failed(e) => Err(e),
+				};
+				let (result, metadata) = match outcome {
+					Ok(outcome) => outcome,
+					Err(e) => {
+						Tournament::<E, M, B>::_restore(
+							&mut self.snapshots,
+							&self.entrants,
+							&[id],
+						)?;
+						return Err(BattleFailed(id, e));
+					}
+				};
+				self.observers.round_complete(id, result, &metadata);
+				let weight = self.graph.node_weight_mut(id).ok_or(RoundNotFound(id))?;
+				*weight = TournamentNode::Round(TournamentRound::Complete {
+					result,
+					metadata,
+					decision: RoundDecision::Played,
+					series: vec![],
+				});
+				self.notify_if_complete(id)?;
+
+				Ok(match result {
+					TournamentRoundResult::A => ent_a,
+					TournamentRoundResult::B => ent_b,
+				})
+			}
+		}
+	}
+}
+
+/// The serialized form of a [`DoubleEliminationTournament`](struct.DoubleEliminationTournament.html), as documented on [`SCHEMA_VERSION`](constant.SCHEMA_VERSION.html).
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "DoubleEliminationTournament")]
+struct DoubleEliminationData<Es, G, W> {
+	version: u32,
+	entrants: Es,
+	graph: G,
+	winners_finals: NodeIndex,
+	losers_finals: NodeIndex,
+	grand_finals: NodeIndex,
+	bracket_reset: Option<NodeIndex>,
+	#[serde(default)]
+	withdrawn: W,
+}
+
+#[cfg(feature = "serde")]
+impl<
+		E: Debug + Display + Clone + Serialize,
+		M: Debug + Display + Clone + Default + Serialize,
+		B: BattleSystem<E, M>,
+	> Serialize for DoubleEliminationTournament<E, M, B>
+{
+	fn serialize<S: Serializer>(
+		&self,
+		serializer: S,
+	) -> std::result::Result<S::Ok, S::Error> {
+		DoubleEliminationData {
+			version: SCHEMA_VERSION,
+			entrants: EntrantsRef(&self.entrants),
+			graph: &self.graph,
+			winners_finals: self.winners_finals,
+			losers_finals: self.losers_finals,
+			grand_finals: self.grand_finals,
+			bracket_reset: self.bracket_reset,
+			withdrawn: &self.withdrawn,
+		}
+		.serialize(serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<
+		'de,
+		E: Debug + Display + Clone + Deserialize<'de>,
+		M: Debug + Display + Clone + Default + Deserialize<'de>,
+		B: BattleSystem<E, M> + Default,
+	> Deserialize<'de> for DoubleEliminationTournament<E, M, B>
+{
+	fn deserialize<D: Deserializer<'de>>(
+		deserializer: D,
+	) -> std::result::Result<Self, D::Error> {
+		let data = DoubleEliminationData::<
+			Vec<E>,
+			Graph<TournamentNode<M>, TournamentEdge>,
+			Vec<EntrantId>,
+		>::deserialize(deserializer)?;
+		check_version(data.version)?;
+		let mut roots =
+			vec![data.winners_finals, data.losers_finals, data.grand_finals];
+		roots.extend(data.bracket_reset);
+		check_bracket(&data.graph, &roots, &data.withdrawn, data.entrants.len())?;
+		Ok(DoubleEliminationTournament {
+			graph: data.graph,
+			entrants: entrant_arcs(data.entrants),
+			winners_finals: data.winners_finals,
+			losers_finals: data.losers_finals,
+			grand_finals: data.grand_finals,
+			bracket_reset: data.bracket_reset,
+			battle_system: B::default(),
+			rng: StdRng::from_entropy(),
+			snapshots: None,
+			observers: Observers::new(),
+			withdrawn: data.withdrawn,
+			phantom_metadata: std::marker::PhantomData,
+		})
+	}
+}
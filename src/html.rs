@@ -0,0 +1,204 @@
+use crate::svg::escape_xml;
+use crate::tournament::*;
+use crate::types::*;
+use petgraph::graph::NodeIndex;
+use std::fmt::{Debug, Display};
+
+/// Inline stylesheet of the HTML report, so that it doesn't need any external assets.
+const STYLE: &str =
+	"body { font-family: sans-serif; margin: 2em; color: #222222; }
+table { border-collapse: collapse; margin-bottom: 1em; }
+th, td { border: 1px solid #cccccc; padding: 0.3em 0.8em; text-align: left; }
+th { background: #f0f0f0; }
+.winner { font-weight: bold; }
+.bracket { overflow-x: auto; }";
+
+/// A place in the standings, and the entrants in it once it's been decided.
+type Placement = (&'static str, Option<Vec<EntrantId>>);
+
+impl<
+		E: Debug + Display + Clone,
+		M: Debug + Display + Clone + Default,
+		B: BattleSystem<E, M>,
+	> Tournament<E, M, B>
+{
+	/// Export a report of the tournament as a single, self-contained HTML page, with no external assets. The report contains:
+	/// - **Standings** - The champion, runner-up and semifinalists, once they've been decided. With a [third place playoff](#method.with_third_place_playoff), the semifinalists are split into third and fourth place.
+	/// - **Bracket** - The bracket, as rendered by [`to_svg()`](#method.to_svg).
+	/// - **Matches** - Every round, from the opening rounds to the grand finals, with both entrants, the winner and the round's metadata, or whether it was a walkover. The third place playoff is listed just before the grand finals.
+	///
+	/// # Example
+	/// ```
+	/// # use ultra_tournament::*;
+	/// # use rand::RngCore;
+	/// # use std::sync::{Arc, RwLock};
+	/// # #[derive(Debug, Clone, Default)]
+	/// # struct MyMetadata;
+	/// # impl MyMetadata {
+	/// # 	fn new() -> Self {
+	/// # 		Self
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Display for MyMetadata {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "My Metadata")
+	/// # 	}
+	/// # }
+	/// # #[derive(Clone, Default)]
+	/// # struct MyBattleSystem;
+	/// # impl BattleSystem<u32, MyMetadata> for MyBattleSystem {
+	/// # 	fn battle(&mut self, a: Arc<RwLock<u32>>, b: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> BattleResult<MyMetadata> {
+	/// # 		if *a.read().unwrap() >= *b.read().unwrap() {
+	/// # 			BattleResult::Solved(TournamentRoundResult::A, MyMetadata::new())
+	/// # 		} else {
+	/// # 			BattleResult::Solved(TournamentRoundResult::B, MyMetadata::new())
+	/// # 		}
+	/// # 	}
+	/// # 	fn tiebreaker(&mut self, _: Arc<RwLock<u32>>, _: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> TiebreakerResult<MyMetadata> {
+	/// # 		Ok((TournamentRoundResult::A, MyMetadata::new()))
+	/// # 	}
+	/// # }
+	/// # fn main() {
+	/// let mut t =
+	/// 	Tournament::<u32, MyMetadata, MyBattleSystem>::new(vec![1, 2, 3, 4, 5]).unwrap();
+	/// t.solve().unwrap();
+	///
+	/// std::fs::write("results.html", t.to_html().unwrap()).unwrap();
+	/// # std::fs::remove_file("results.html").unwrap();
+	/// # }
+	/// ```
+	pub fn to_html(&self) -> Result<String> {
+		let mut lines = vec![
+			"<!DOCTYPE html>".to_string(),
+			"<html>".to_string(),
+			"<head>".to_string(),
+			"<meta charset=\"utf-8\">".to_string(),
+			"<title>Tournament results</title>".to_string(),
+			format!("<style>\n{}\n</style>", STYLE),
+			"</head>".to_string(),
+			"<body>".to_string(),
+			"<h1>Tournament results</h1>".to_string(),
+		];
+
+		lines.push("<h2>Standings</h2>".to_string());
+		lines.push("<table>".to_string());
+		for (place, entrants) in self.placements()? {
+			let names = match entrants {
+				Some(entrants) => entrants
+					.iter()
+					.map(|eid| Ok(escape_xml(&self.entrant_name(*eid)?)))
+					.collect::<Result<Vec<String>>>()?
+					.join(", "),
+				None => "Undecided".to_string(),
+			};
+			lines.push(format!("<tr><th>{}</th><td>{}</td></tr>", place, names));
+		}
+		lines.push("</table>".to_string());
+
+		lines.push("<h2>Bracket</h2>".to_string());
+		lines.push("<div class=\"bracket\">".to_string());
+		lines.push(self.to_svg()?.trim_end().to_string());
+		lines.push("</div>".to_string());
+
+		lines.push("<h2>Matches</h2>".to_string());
+		let mut sections: Vec<(String, Vec<NodeIndex>)> = self
+			.layers()?
+			.into_iter()
+			.enumerate()
+			.rev()
+			.map(|(depth, layer)| (round_name(depth), layer))
+			.collect();
+		if let Some(third_place) = self.third_place() {
+			// Just before the grand finals.
+			let finals = sections.len() - 1;
+			sections.insert(
+				finals,
+				("Third place playoff".to_string(), vec![*third_place]),
+			);
+		}
+		for (title, rounds) in sections {
+			lines.push(format!("<h3>{}</h3>", title));
+			lines.push("<table>".to_string());
+			lines.push(
+				"<tr><th>Round</th><th>A</th><th>B</th><th>Details</th></tr>"
+					.to_string(),
+			);
+			for id in rounds {
+				lines.push(self.match_row(id)?);
+			}
+			lines.push("</table>".to_string());
+		}
+
+		lines.push("</body>".to_string());
+		lines.push("</html>".to_string());
+		Ok(lines.join("\n") + "\n")
+	}
+
+	/// The champion, runner-up and semifinalists, or third and fourth place if there's a playoff, or `None` for places that haven't been decided yet.
+	fn placements(&self) -> Result<Vec<Placement>> {
+		let grand_finals = *self.grand_finals();
+		let mut placements = vec![
+			("Champion", self.winner(grand_finals)?.map(|eid| vec![eid])),
+			("Runner-up", self.loser(grand_finals)?.map(|eid| vec![eid])),
+		];
+		if let Some(third_place) = self.third_place() {
+			placements.push((
+				"Third place",
+				self.winner(*third_place)?.map(|eid| vec![eid]),
+			));
+			placements.push((
+				"Fourth place",
+				self.loser(*third_place)?.map(|eid| vec![eid]),
+			));
+		} else if self.graph()[grand_finals].round().is_some() {
+			let (a, b) = self.child_nodes(grand_finals)?;
+			let semis: Vec<NodeIndex> = [a, b]
+				.iter()
+				.copied()
+				.filter(|id| self.graph()[*id].round().is_some())
+				.collect();
+			if !semis.is_empty() {
+				let losers = semis
+					.iter()
+					.map(|id| self.loser(*id))
+					.collect::<Result<Option<Vec<EntrantId>>>>()?;
+				placements.push(("Semifinalists", losers));
+			}
+		}
+		Ok(placements)
+	}
+
+	/// A row of the match list, with the winner in bold.
+	fn match_row(&self, id: NodeIndex) -> Result<String> {
+		let (a, b) = self.child_nodes(id)?;
+		let winner = self.winner(id)?;
+		let mut cells = vec![id.index().to_string()];
+		for side in [a, b].iter() {
+			cells.push(match self.winner(*side)? {
+				Some(eid) if Some(eid) == winner => format!(
+					"<span class=\"winner\">{}</span>",
+					escape_xml(&self.entrant_name(eid)?)
+				),
+				Some(eid) => escape_xml(&self.entrant_name(eid)?),
+				None => "TBD".to_string(),
+			});
+		}
+		let node = &self.graph()[id];
+		cells.push(match (node.decision(), node.metadata()) {
+			(Some(RoundDecision::Walkover), _) => "Walkover".to_string(),
+			(_, Some(metadata)) => escape_xml(&metadata.to_string()),
+			_ => "Not played yet".to_string(),
+		});
+		Ok(format!("<tr><td>{}</td></tr>", cells.join("</td><td>")))
+	}
+}
+
+/// The name of the rounds `depth` layers below the grand finals.
+fn round_name(depth: usize) -> String {
+	match depth {
+		0 => "Final".to_string(),
+		1 => "Semifinals".to_string(),
+		2 => "Quarterfinals".to_string(),
+		_ => format!("Round of {}", 2usize.pow(depth as u32 + 1)),
+	}
+}
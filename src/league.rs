@@ -0,0 +1,311 @@
+use crate::types::*;
+use rand::RngCore;
+use std::clone::Clone;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::fmt::{Debug, Display};
+use std::sync::{Arc, RwLock};
+
+/// The result of a match in a format where ties count as draws, such as a [`RoundRobin`](struct.RoundRobin.html).
+///
+/// Convertible from [`TournamentRoundResult`](enum.TournamentRoundResult.html)
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MatchResult {
+	/// Represents the winner being on side `A`.
+	A,
+	/// Represents the winner being on side `B`.
+	B,
+	/// Represents a draw, as returned by [`BattleResult::Tie`](enum.BattleResult.html#variant.Tie).
+	Tie,
+}
+impl std::convert::From<TournamentRoundResult> for MatchResult {
+	fn from(r: TournamentRoundResult) -> Self {
+		match r {
+			TournamentRoundResult::A => Self::A,
+			TournamentRoundResult::B => Self::B,
+		}
+	}
+}
+impl fmt::Display for MatchResult {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::A => write!(f, "A wins"),
+			Self::B => write!(f, "B wins"),
+			Self::Tie => write!(f, "Tie"),
+		}
+	}
+}
+
+/// A single match between two entrants in a league format.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LeagueMatch<M: Debug + Display + Clone + Default> {
+	/// The entrant on side `A`.
+	pub a: EntrantId,
+	/// The entrant on side `B`.
+	pub b: EntrantId,
+	/// The index of the round of the schedule this match is played in.
+	pub round: usize,
+	/// The result of the match and its metadata, or `None` if the match hasn't been played yet. Drawn matches get `M::default()` as their metadata.
+	pub outcome: Option<(MatchResult, M)>,
+}
+impl<M: Debug + Display + Clone + Default> LeagueMatch<M> {
+	/// Get the result of the match. Returns `None` if the match hasn't been played yet.
+	pub fn result(&self) -> Option<&MatchResult> {
+		self.outcome.as_ref().map(|(result, _)| result)
+	}
+	/// Get the metadata of the match. Returns `None` if the match hasn't been played yet.
+	pub fn metadata(&self) -> Option<&M> {
+		self.outcome.as_ref().map(|(_, metadata)| metadata)
+	}
+	/// Get the opponent of `entrant` in this match. Returns `None` if `entrant` doesn't play in it.
+	pub fn opponent(&self, entrant: EntrantId) -> Option<EntrantId> {
+		if self.a == entrant {
+			Some(self.b)
+		} else if self.b == entrant {
+			Some(self.a)
+		} else {
+			None
+		}
+	}
+	/// Get the points earned by `entrant` in this match. Returns `None` if the match hasn't been played yet, or `entrant` doesn't play in it.
+	pub fn points(
+		&self,
+		entrant: EntrantId,
+		points: &PointSystem,
+	) -> Option<u32> {
+		self.opponent(entrant)?;
+		Some(match self.result()? {
+			MatchResult::Tie => points.tie,
+			MatchResult::A if self.a == entrant => points.win,
+			MatchResult::B if self.b == entrant => points.win,
+			_ => points.loss,
+		})
+	}
+}
+
+/// Plays an incomplete match with `battle_system`, storing its result. Ties are recorded as draws instead of going to [`tiebreaker()`](trait.BattleSystem.html#tymethod.tiebreaker). If the battle system fails, the match is left unplayed and the error is returned as [`TournamentError::MatchFailed`](enum.TournamentError.html#variant.MatchFailed), with `index` as the match's index.
+pub(crate) fn play_match<
+	E: Debug + Display + Clone,
+	M: Debug + Display + Clone + Default,
+	B: BattleSystem<E, M>,
+>(
+	m: &mut LeagueMatch<M>,
+	index: usize,
+	entrants: &[Arc<RwLock<E>>],
+	battle_system: &mut B,
+	rng: &mut dyn RngCore,
+) -> Result<MatchResult> {
+	use TournamentError::*;
+	let arc_a = entrants.get(m.a.0).ok_or(EntrantNotFound(m.a))?;
+	let arc_b = entrants.get(m.b.0).ok_or(EntrantNotFound(m.b))?;
+	let (result, metadata) =
+		match battle_system.battle(arc_a.clone(), arc_b.clone(), rng) {
+			BattleResult::Solved(round_result, metadata) => {
+				(MatchResult::from(round_result), metadata)
+			}
+			BattleResult::Tie => (MatchResult::Tie, M::default()),
+			BattleResult::Failed(e) => return Err(MatchFailed(index, e)),
+		};
+	m.outcome = Some((result, metadata));
+	Ok(result)
+}
+
+/// The number of points awarded for each match result in a league format.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PointSystem {
+	/// Points awarded for winning a match.
+	pub win: u32,
+	/// Points awarded for drawing a match.
+	pub tie: u32,
+	/// Points awarded for losing a match.
+	pub loss: u32,
+}
+impl PointSystem {
+	/// Create a new `PointSystem`.
+	pub fn new(win: u32, tie: u32, loss: u32) -> Self {
+		Self { win, tie, loss }
+	}
+}
+impl Default for PointSystem {
+	/// Three points for a win, one for a draw and none for a loss.
+	fn default() -> Self {
+		Self::new(3, 1, 0)
+	}
+}
+
+/// Criteria used to order entrants in a standings table that are level on points. Applied in order, and entrants that remain tied after all of them are ordered by [`EntrantId`](struct.EntrantId.html).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LeagueTiebreaker {
+	/// Points earned in matches played between the tied entrants only.
+	HeadToHead,
+	/// Number of matches won.
+	Wins,
+	/// The sum of the points of every opponent faced.
+	Buchholz,
+	/// The sum of the points of every opponent beaten, plus half the points of every opponent drawn with.
+	SonnebornBerger,
+}
+
+/// A single row of a league standings table.
+#[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LeagueStanding {
+	/// The entrant this row belongs to.
+	pub entrant: EntrantId,
+	/// Number of matches played.
+	pub played: u32,
+	/// Number of matches won.
+	pub wins: u32,
+	/// Number of matches lost.
+	pub losses: u32,
+	/// Number of matches drawn.
+	pub ties: u32,
+	/// Number of byes received. A bye is worth the same number of points as a win.
+	pub byes: u32,
+	/// Total points, as awarded by the [`PointSystem`](struct.PointSystem.html).
+	pub points: u32,
+	/// The [Buchholz](enum.LeagueTiebreaker.html#variant.Buchholz) score.
+	pub buchholz: u32,
+	/// The [Sonneborn-Berger](enum.LeagueTiebreaker.html#variant.SonnebornBerger) score, counted in halves so that it stays whole: twice the points of every opponent beaten, plus the points of every opponent drawn with.
+	pub sonneborn_berger: u32,
+}
+impl fmt::Display for LeagueStanding {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(
+			f,
+			"{}: {} pts ({}W {}T {}L)",
+			self.entrant, self.points, self.wins, self.ties, self.losses
+		)
+	}
+}
+
+/// Computes a standings table from a list of matches and byes, sorted from first to last place.
+pub(crate) fn standings<M: Debug + Display + Clone + Default>(
+	len_entrants: usize,
+	matches: &[LeagueMatch<M>],
+	byes: &[EntrantId],
+	points: &PointSystem,
+	tiebreakers: &[LeagueTiebreaker],
+) -> Vec<LeagueStanding> {
+	let mut table: Vec<LeagueStanding> = (0..len_entrants)
+		.map(|i| LeagueStanding {
+			entrant: EntrantId(i),
+			played: 0,
+			wins: 0,
+			losses: 0,
+			ties: 0,
+			byes: 0,
+			points: 0,
+			buchholz: 0,
+			sonneborn_berger: 0,
+		})
+		.collect();
+
+	let played: Vec<&LeagueMatch<M>> =
+		matches.iter().filter(|m| m.result().is_some()).collect();
+	for m in played.iter() {
+		for &(entrant, side) in
+			[(m.a, MatchResult::A), (m.b, MatchResult::B)].iter()
+		{
+			let row = &mut table[entrant.0];
+			row.played += 1;
+			match m.result() {
+				Some(MatchResult::Tie) => row.ties += 1,
+				Some(result) if *result == side => row.wins += 1,
+				_ => row.losses += 1,
+			}
+			row.points += m.points(entrant, points).unwrap_or(0);
+		}
+	}
+	for bye in byes {
+		let row = &mut table[bye.0];
+		row.byes += 1;
+		row.points += points.win;
+	}
+	for m in played.iter() {
+		for &(entrant, opponent, side) in
+			[(m.a, m.b, MatchResult::A), (m.b, m.a, MatchResult::B)].iter()
+		{
+			let opponent_points = table[opponent.0].points;
+			let row = &mut table[entrant.0];
+			row.buchholz += opponent_points;
+			row.sonneborn_berger += match m.result() {
+				Some(MatchResult::Tie) => opponent_points,
+				Some(result) if *result == side => 2 * opponent_points,
+				_ => 0,
+			};
+		}
+	}
+
+	let mut order: Vec<usize> = (0..len_entrants).collect();
+	order.sort_by(|a, b| table[*b].points.cmp(&table[*a].points));
+	let mut ranked = vec![];
+	for group in split_ties(order, |i| table[i].points) {
+		ranked.extend(break_ties(&table, &played, points, group, tiebreakers));
+	}
+	ranked.into_iter().map(|i| table[i].clone()).collect()
+}
+
+/// Splits an already sorted list into runs of equal keys.
+fn split_ties<K: Eq>(
+	order: Vec<usize>,
+	key: impl Fn(usize) -> K,
+) -> Vec<Vec<usize>> {
+	let mut groups: Vec<Vec<usize>> = vec![];
+	for i in order {
+		match groups.last_mut() {
+			Some(group) if key(group[0]) == key(i) => group.push(i),
+			_ => groups.push(vec![i]),
+		}
+	}
+	groups
+}
+
+fn break_ties<M: Debug + Display + Clone + Default>(
+	table: &[LeagueStanding],
+	played: &[&LeagueMatch<M>],
+	points: &PointSystem,
+	mut group: Vec<usize>,
+	tiebreakers: &[LeagueTiebreaker],
+) -> Vec<usize> {
+	let (tiebreaker, rest) = match tiebreakers.split_first() {
+		Some(split) if group.len() > 1 => split,
+		_ => {
+			group.sort();
+			return group;
+		}
+	};
+	let keys: HashMap<usize, u32> = group
+		.iter()
+		.map(|&i| {
+			let key = match tiebreaker {
+				LeagueTiebreaker::HeadToHead => played
+					.iter()
+					.filter(|m| {
+						m.opponent(EntrantId(i))
+							.is_some_and(|opponent| group.contains(&opponent.0))
+					})
+					.filter_map(|m| m.points(EntrantId(i), points))
+					.sum(),
+				LeagueTiebreaker::Wins => table[i].wins,
+				LeagueTiebreaker::Buchholz => table[i].buchholz,
+				LeagueTiebreaker::SonnebornBerger => table[i].sonneborn_berger,
+			};
+			(i, key)
+		})
+		.collect();
+	let key = |i: usize| keys[&i];
+	group.sort_by(|a, b| match key(*b).cmp(&key(*a)) {
+		Ordering::Equal => a.cmp(b),
+		ordering => ordering,
+	});
+	split_ties(group, key)
+		.into_iter()
+		.flat_map(|sub| break_ties(table, played, points, sub, rest))
+		.collect()
+}
@@ -1,4 +1,13 @@
-//! `ultra_tournament` is a crate for running single-elimination tournament brackets with arbitrary structs for the entrants and round computation.
+//! `ultra_tournament` is a crate for running single-elimination and double-elimination tournament brackets, round-robin leagues and Swiss-system tournaments with arbitrary structs for the entrants and round computation.
+//!
+//! # Features
+//! - **`parallel`** - Adds [`Tournament::solve_parallel()`](struct.Tournament.html#method.solve_parallel), which solves independent parts of the bracket at the same time on [rayon](https://docs.rs/rayon)'s thread pool.
+//! - **`serde`** - Implements `Serialize` and `Deserialize` for every tournament format, so that half-played tournaments can be saved and loaded again. The on-disk schema is documented and versioned by [`SCHEMA_VERSION`](constant.SCHEMA_VERSION.html).
+//!
+//! # Upgrading battle systems
+//! [`BattleSystem::battle()`](trait.BattleSystem.html#tymethod.battle) and [`tiebreaker()`](trait.BattleSystem.html#tymethod.tiebreaker) take `&mut self` and the tournament's random number generator, and the tiebreaker returns a [`TiebreakerResult`](type.TiebreakerResult.html) so that it can fail. Tournaments own an instance of their battle system, which must implement `Default` to use constructors such as [`Tournament::new()`](struct.Tournament.html#method.new). Battle systems written against the old signatures can either:
+//! - Be updated: add `&mut self` and `rng: &mut dyn RngCore` to both functions, wrap the tiebreaker's return value in `Ok`, and derive `Default`.
+//! - Be kept as they are: implement [`StatelessBattleSystem`](trait.StatelessBattleSystem.html) instead of `BattleSystem`, and use [`Stateless`](struct.Stateless.html)`<MySystem>` as the tournament's battle system.
 //!
 //! # Example
 //! ```
@@ -20,13 +29,15 @@
 //! 	}
 //! }
 //!
-//! #[derive(Clone)]
+//! #[derive(Clone, Default)]
 //! struct IntBattleSystem;
 //!
 //! impl BattleSystem<IntFighter, String> for IntBattleSystem {
 //! 	fn battle(
+//! 		&mut self,
 //! 		a_arc: Arc<RwLock<IntFighter>>,
 //! 		b_arc: Arc<RwLock<IntFighter>>,
+//! 		_: &mut dyn RngCore,
 //! 	) -> BattleResult<String> {
 //! 		use TournamentRoundResult::*;
 //! 		let a = *a_arc.read().unwrap();
@@ -42,24 +53,75 @@
 //! 		BattleResult::Solved(winner, format!("{} wins by {}!", winner_val, delta))
 //! 	}
 //! 	fn tiebreaker(
+//! 		&mut self,
 //! 		_: Arc<RwLock<IntFighter>>,
 //! 		_: Arc<RwLock<IntFighter>>,
-//! 	) -> (TournamentRoundResult, String) {
+//! 		rng: &mut dyn RngCore,
+//! 	) -> TiebreakerResult<String> {
 //! 		use TournamentRoundResult::*;
-//! 		let res: f32 = random();
+//! 		let res: f32 = rng.gen();
 //! 		if res > 0.5 {
-//! 			(A, "A won by random tiebreaker.".to_string())
+//! 			Ok((A, "A won by random tiebreaker.".to_string()))
 //! 		} else {
-//! 			(B, "B won by random tiebreaker.".to_string())
+//! 			Ok((B, "B won by random tiebreaker.".to_string()))
 //! 		}
 //! 	}
 //! }
+//! # fn main() {}
 //! ```
+#![allow(clippy::tabs_in_doc_comments)]
+
+#[warn(missing_docs)]
+mod dot;
+#[warn(missing_docs)]
+mod double_elimination;
+#[warn(missing_docs)]
+mod html;
+#[warn(missing_docs)]
+mod league;
+#[warn(missing_docs)]
+mod observer;
+#[warn(missing_docs)]
+mod round_robin;
+#[warn(missing_docs)]
+mod seeding;
+#[cfg(feature = "serde")]
+#[warn(missing_docs)]
+mod serialization;
+#[warn(missing_docs)]
+mod series;
+#[warn(missing_docs)]
+mod simulation;
+#[warn(missing_docs)]
+mod standings;
+#[warn(missing_docs)]
+mod svg;
+#[warn(missing_docs)]
+mod swiss;
 #[warn(missing_docs)]
 mod tournament;
 #[warn(missing_docs)]
 mod types;
 
+#[doc(inline)]
+pub use crate::double_elimination::*;
+#[doc(inline)]
+pub use crate::league::*;
+#[doc(inline)]
+pub use crate::observer::*;
+#[doc(inline)]
+pub use crate::round_robin::*;
+#[doc(inline)]
+pub use crate::seeding::*;
+#[cfg(feature = "serde")]
+#[doc(inline)]
+pub use crate::serialization::SCHEMA_VERSION;
+#[doc(inline)]
+pub use crate::simulation::*;
+#[doc(inline)]
+pub use crate::standings::*;
+#[doc(inline)]
+pub use crate::swiss::*;
 #[doc(inline)]
 pub use crate::tournament::*;
 #[doc(inline)]
@@ -68,5 +130,11 @@ pub use crate::types::*;
 #[cfg(test)]
 mod test {
 	mod test_docs;
+	mod test_double_elimination;
+	mod test_janken;
+	mod test_round_robin;
+	#[cfg(feature = "serde")]
+	mod test_serde;
+	mod test_swiss;
 	mod test_tournament;
 }
@@ -0,0 +1,141 @@
+use crate::types::*;
+use petgraph::graph::NodeIndex;
+use std::fmt;
+use std::fmt::{Debug, Display};
+
+/// Receives events while a [`Tournament`](struct.Tournament.html) or [`DoubleEliminationTournament`](struct.DoubleEliminationTournament.html) is being solved, such as to stream play-by-play to a UI or a log. Registered with [`Tournament::add_observer()`](struct.Tournament.html#method.add_observer).
+///
+/// Every method does nothing by default, so implementations only need to override the events they care about. Events are sent in the order rounds are played.
+///
+/// # Example
+/// Print every round as it's played
+/// ```
+/// # use ultra_tournament::*;
+/// # use petgraph::graph::NodeIndex;
+/// # use rand::RngCore;
+/// # use std::sync::{Arc, RwLock};
+/// # #[derive(Debug, Clone, Default)]
+/// # struct MyMetadata;
+/// # impl MyMetadata {
+/// # 	fn new() -> Self {
+/// # 		Self
+/// # 	}
+/// # }
+/// # impl std::fmt::Display for MyMetadata {
+/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// # 		write!(f, "My Metadata")
+/// # 	}
+/// # }
+/// # #[derive(Clone, Default)]
+/// # struct MyBattleSystem;
+/// # impl BattleSystem<u32, MyMetadata> for MyBattleSystem {
+/// # 	fn battle(&mut self, a: Arc<RwLock<u32>>, b: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> BattleResult<MyMetadata> {
+/// # 		if *a.read().unwrap() >= *b.read().unwrap() {
+/// # 			BattleResult::Solved(TournamentRoundResult::A, MyMetadata::new())
+/// # 		} else {
+/// # 			BattleResult::Solved(TournamentRoundResult::B, MyMetadata::new())
+/// # 		}
+/// # 	}
+/// # 	fn tiebreaker(&mut self, _: Arc<RwLock<u32>>, _: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> TiebreakerResult<MyMetadata> {
+/// # 		Ok((TournamentRoundResult::A, MyMetadata::new()))
+/// # 	}
+/// # }
+/// # fn main() {
+/// struct PrintObserver;
+/// impl TournamentObserver<MyMetadata> for PrintObserver {
+/// 	fn on_round_start(&mut self, node: NodeIndex, a: EntrantId, b: EntrantId) {
+/// 		println!("Round {}: {} vs {}", node.index(), a, b);
+/// 	}
+/// 	fn on_round_complete(
+/// 		&mut self,
+/// 		node: NodeIndex,
+/// 		result: TournamentRoundResult,
+/// 		metadata: &MyMetadata,
+/// 	) {
+/// 		println!("Round {}: {} ({})", node.index(), result, metadata);
+/// 	}
+/// 	fn on_tournament_complete(&mut self, winner: EntrantId) {
+/// 		println!("{} wins the tournament!", winner);
+/// 	}
+/// }
+///
+/// let mut t: Tournament<u32, MyMetadata, MyBattleSystem> =
+/// 	Tournament::new(vec![1, 2, 3, 4, 5]).unwrap();
+/// t.add_observer(PrintObserver);
+/// t.solve().unwrap();
+/// # }
+/// ```
+pub trait TournamentObserver<M: Debug + Display + Clone + Default> {
+	/// Called when round `node` is about to be solved by the battle system, between entrants `a` and `b`.
+	fn on_round_start(&mut self, _node: NodeIndex, _a: EntrantId, _b: EntrantId) {
+	}
+
+	/// Called when the battle system returns a [`BattleResult::Tie`](enum.BattleResult.html#variant.Tie) for round `node`, before its [`tiebreaker()`](trait.BattleSystem.html#tymethod.tiebreaker) is run.
+	fn on_tie(&mut self, _node: NodeIndex) {}
+
+	/// Called when round `node` has been completed, either by the battle system or by a reported result.
+	fn on_round_complete(
+		&mut self,
+		_node: NodeIndex,
+		_result: TournamentRoundResult,
+		_metadata: &M,
+	) {
+	}
+
+	/// Called once the tournament's champion has been decided.
+	fn on_tournament_complete(&mut self, _winner: EntrantId) {}
+}
+
+/// The observers registered on a tournament, which forwards every event to each of them in the order they were added.
+pub(crate) struct Observers<M: Debug + Display + Clone + Default>(
+	Vec<Box<dyn TournamentObserver<M> + Send>>,
+);
+impl<M: Debug + Display + Clone + Default> Observers<M> {
+	pub(crate) fn new() -> Self {
+		Self(vec![])
+	}
+	pub(crate) fn add(
+		&mut self,
+		observer: impl TournamentObserver<M> + Send + 'static,
+	) {
+		self.0.push(Box::new(observer));
+	}
+	pub(crate) fn clear(&mut self) {
+		self.0.clear();
+	}
+	pub(crate) fn round_start(
+		&mut self,
+		node: NodeIndex,
+		a: EntrantId,
+		b: EntrantId,
+	) {
+		for observer in self.0.iter_mut() {
+			observer.on_round_start(node, a, b);
+		}
+	}
+	pub(crate) fn tie(&mut self, node: NodeIndex) {
+		for observer in self.0.iter_mut() {
+			observer.on_tie(node);
+		}
+	}
+	pub(crate) fn round_complete(
+		&mut self,
+		node: NodeIndex,
+		result: TournamentRoundResult,
+		metadata: &M,
+	) {
+		for observer in self.0.iter_mut() {
+			observer.on_round_complete(node, result, metadata);
+		}
+	}
+	pub(crate) fn tournament_complete(&mut self, winner: EntrantId) {
+		for observer in self.0.iter_mut() {
+			observer.on_tournament_complete(winner);
+		}
+	}
+}
+impl<M: Debug + Display + Clone + Default> fmt::Debug for Observers<M> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Observers({})", self.0.len())
+	}
+}
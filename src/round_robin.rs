@@ -0,0 +1,298 @@
+use crate::league::*;
+#[cfg(feature = "serde")]
+use crate::serialization::*;
+use crate::types::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::clone::Clone;
+use std::default::Default;
+use std::fmt::{Debug, Display};
+use std::sync::{Arc, RwLock};
+
+/// `RoundRobin<E, M, B>` creates a league where every entrant plays every other entrant a set number of times.
+///
+/// Unlike in a [`Tournament`](struct.Tournament.html), a [`BattleResult::Tie`](enum.BattleResult.html#variant.Tie) is recorded as a draw instead of going to [`tiebreaker()`](trait.BattleSystem.html#tymethod.tiebreaker). Entrants are ranked in a [standings table](#method.standings) by points, as awarded by a [`PointSystem`](struct.PointSystem.html), then by a list of [`LeagueTiebreaker`](enum.LeagueTiebreaker.html)s.
+///
+/// Type parameters are identical to those of [`Tournament`](struct.Tournament.html).
+#[derive(Debug)]
+pub struct RoundRobin<
+	E: Debug + Display + Clone,
+	M: Debug + Display + Clone + Default,
+	B: BattleSystem<E, M>,
+> {
+	entrants: Vec<Arc<RwLock<E>>>,
+	matches: Vec<LeagueMatch<M>>,
+	len_rounds: usize,
+	points: PointSystem,
+	tiebreakers: Vec<LeagueTiebreaker>,
+	battle_system: B,
+	rng: StdRng,
+}
+
+impl<
+		E: Debug + Display + Clone,
+		M: Debug + Display + Clone + Default,
+		B: BattleSystem<E, M> + Default,
+	> RoundRobin<E, M, B>
+{
+	/// Create a new `RoundRobin` from a `Vec<E>` of entrant structs, where every entrant plays every other entrant `legs` times.
+	///
+	/// Matches are scheduled into rounds with the circle method, so that nobody plays twice in the same round. Sides `A` and `B` are swapped on every other leg.
+	pub fn new(entrants: Vec<E>, legs: usize) -> Result<Self> {
+		Self::new_with_battle_system(entrants, legs, B::default())
+	}
+}
+
+impl<
+		E: Debug + Display + Clone,
+		M: Debug + Display + Clone + Default,
+		B: BattleSystem<E, M>,
+	> RoundRobin<E, M, B>
+{
+	/// Create a new `RoundRobin` as per [`new()`](#method.new), where matches are solved with the supplied instance of the battle system.
+	pub fn new_with_battle_system(
+		entrants: Vec<E>,
+		legs: usize,
+		battle_system: B,
+	) -> Result<Self> {
+		if entrants.is_empty() {
+			return Err(TournamentError::NeedsAtLeastOneEntrant);
+		}
+		if legs == 0 {
+			return Err(TournamentError::NeedsAtLeastOneLeg);
+		}
+
+		let entrant_arcs: Vec<Arc<RwLock<E>>> = entrants
+			.into_iter()
+			.map(|entrant| Arc::new(RwLock::new(entrant)))
+			.collect();
+
+		// A `None` slot gives a bye to whoever it's paired with.
+		let mut slots: Vec<Option<EntrantId>> = (0..entrant_arcs.len())
+			.map(|i| Some(EntrantId(i)))
+			.collect();
+		if slots.len() % 2 == 1 {
+			slots.push(None);
+		}
+		let rounds_per_leg = slots.len() - 1;
+
+		let mut matches = vec![];
+		for leg in 0..legs {
+			let mut rotation = slots.clone();
+			for r in 0..rounds_per_leg {
+				for i in 0..rotation.len() / 2 {
+					if let (Some(x), Some(y)) =
+						(rotation[i], rotation[rotation.len() - 1 - i])
+					{
+						// Keep the fixed slot from always playing on the same side.
+						let swap = (leg % 2 == 1) != (i == 0 && r % 2 == 1);
+						let (a, b) = if swap { (y, x) } else { (x, y) };
+						matches.push(LeagueMatch {
+							a,
+							b,
+							round: leg * rounds_per_leg + r,
+							outcome: None,
+						});
+					}
+				}
+				rotation[1..].rotate_right(1);
+			}
+		}
+
+		Ok(RoundRobin::<E, M, B> {
+			entrants: entrant_arcs,
+			matches,
+			len_rounds: legs * rounds_per_leg,
+			points: PointSystem::default(),
+			tiebreakers: vec![
+				LeagueTiebreaker::HeadToHead,
+				LeagueTiebreaker::Wins,
+				LeagueTiebreaker::SonnebornBerger,
+			],
+			battle_system,
+			rng: StdRng::from_entropy(),
+		})
+	}
+
+	/// Get the number of entrants in the league.
+	pub fn len_entrants(&self) -> usize {
+		self.entrants.len()
+	}
+
+	/// Get the number of rounds in the league's schedule.
+	pub fn len_rounds(&self) -> usize {
+		self.len_rounds
+	}
+
+	/// Get the number of matches in the league, played and unplayed.
+	pub fn len_matches(&self) -> usize {
+		self.matches.len()
+	}
+
+	/// Get the number of matches that have been played.
+	pub fn len_matches_complete(&self) -> usize {
+		self.matches.iter().filter(|m| m.result().is_some()).count()
+	}
+
+	/// Get an `Arc<RwLock<E>>` encapsulating an entrant of specified [`EntrantId`](struct.EntrantId.html)
+	pub fn entrant(&self, id: EntrantId) -> Arc<RwLock<E>> {
+		self.entrants.get(id.0).unwrap().clone()
+	}
+
+	/// Get a ref to every [`LeagueMatch`](struct.LeagueMatch.html) in the league, in the order they're scheduled.
+	pub fn matches(&self) -> &Vec<LeagueMatch<M>> {
+		&self.matches
+	}
+
+	/// Get a ref to the battle system used to solve matches.
+	pub fn battle_system(&self) -> &B {
+		&self.battle_system
+	}
+
+	/// Get a mutable ref to the battle system used to solve matches, to change its configuration between matches.
+	pub fn battle_system_mut(&mut self) -> &mut B {
+		&mut self.battle_system
+	}
+
+	/// Seed the random number generator passed to the battle system, so that solving the league is reproducible. By default, it's seeded from system entropy.
+	pub fn set_seed(&mut self, seed: u64) {
+		self.rng = StdRng::seed_from_u64(seed);
+	}
+
+	/// Replace the random number generator passed to the battle system.
+	pub fn set_rng(&mut self, rng: StdRng) {
+		self.rng = rng;
+	}
+
+	/// Get the [`PointSystem`](struct.PointSystem.html) used by the standings table. Defaults to three points for a win and one for a draw.
+	pub fn points(&self) -> &PointSystem {
+		&self.points
+	}
+
+	/// Set the [`PointSystem`](struct.PointSystem.html) used by the standings table.
+	pub fn set_points(&mut self, points: PointSystem) {
+		self.points = points;
+	}
+
+	/// Get the [`LeagueTiebreaker`](enum.LeagueTiebreaker.html)s used to order entrants level on points. Defaults to [`HeadToHead`](enum.LeagueTiebreaker.html#variant.HeadToHead), [`Wins`](enum.LeagueTiebreaker.html#variant.Wins), then [`SonnebornBerger`](enum.LeagueTiebreaker.html#variant.SonnebornBerger).
+	pub fn tiebreakers(&self) -> &Vec<LeagueTiebreaker> {
+		&self.tiebreakers
+	}
+
+	/// Set the [`LeagueTiebreaker`](enum.LeagueTiebreaker.html)s used to order entrants level on points, in order of priority.
+	pub fn set_tiebreakers(&mut self, tiebreakers: Vec<LeagueTiebreaker>) {
+		self.tiebreakers = tiebreakers;
+	}
+
+	/// Plays every match in the league that hasn't been played yet, in schedule order.
+	pub fn solve(&mut self) -> Result<()> {
+		for i in 0..self.matches.len() {
+			self.solve_match(i)?;
+		}
+		Ok(())
+	}
+
+	/// Plays every match of a single round of the schedule that hasn't been played yet.
+	pub fn solve_round(&mut self, round: usize) -> Result<()> {
+		for i in 0..self.matches.len() {
+			if self.matches[i].round == round {
+				self.solve_match(i)?;
+			}
+		}
+		Ok(())
+	}
+
+	/// Plays the match at index `id` of [`matches()`](#method.matches), returning its result. Matches that have already been played aren't replayed. If the battle system [fails](enum.BattleResult.html#variant.Failed), the match is left unplayed and [`TournamentError::MatchFailed`](enum.TournamentError.html#variant.MatchFailed) is returned.
+	pub fn solve_match(&mut self, id: usize) -> Result<MatchResult> {
+		let m = self
+			.matches
+			.get_mut(id)
+			.ok_or(TournamentError::MatchNotFound(id))?;
+		match m.result() {
+			Some(result) => Ok(*result),
+			None => play_match(
+				m,
+				id,
+				&self.entrants,
+				&mut self.battle_system,
+				&mut self.rng,
+			),
+		}
+	}
+
+	/// Get the standings table of the league, from first to last place. Only matches that have been played are counted.
+	pub fn standings(&self) -> Vec<LeagueStanding> {
+		standings(
+			self.entrants.len(),
+			&self.matches,
+			&[],
+			&self.points,
+			&self.tiebreakers,
+		)
+	}
+}
+
+/// The serialized form of a [`RoundRobin`](struct.RoundRobin.html), as documented on [`SCHEMA_VERSION`](constant.SCHEMA_VERSION.html).
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "RoundRobin")]
+struct RoundRobinData<Es, Ms> {
+	version: u32,
+	entrants: Es,
+	matches: Ms,
+	len_rounds: usize,
+	points: PointSystem,
+	tiebreakers: Vec<LeagueTiebreaker>,
+}
+
+#[cfg(feature = "serde")]
+impl<
+		E: Debug + Display + Clone + Serialize,
+		M: Debug + Display + Clone + Default + Serialize,
+		B: BattleSystem<E, M>,
+	> Serialize for RoundRobin<E, M, B>
+{
+	fn serialize<S: Serializer>(
+		&self,
+		serializer: S,
+	) -> std::result::Result<S::Ok, S::Error> {
+		RoundRobinData {
+			version: SCHEMA_VERSION,
+			entrants: EntrantsRef(&self.entrants),
+			matches: &self.matches,
+			len_rounds: self.len_rounds,
+			points: self.points,
+			tiebreakers: self.tiebreakers.clone(),
+		}
+		.serialize(serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<
+		'de,
+		E: Debug + Display + Clone + Deserialize<'de>,
+		M: Debug + Display + Clone + Default + Deserialize<'de>,
+		B: BattleSystem<E, M> + Default,
+	> Deserialize<'de> for RoundRobin<E, M, B>
+{
+	fn deserialize<D: Deserializer<'de>>(
+		deserializer: D,
+	) -> std::result::Result<Self, D::Error> {
+		let data =
+			RoundRobinData::<Vec<E>, Vec<LeagueMatch<M>>>::deserialize(deserializer)?;
+		check_version(data.version)?;
+		check_matches(&data.matches, &[], data.entrants.len())?;
+		Ok(RoundRobin {
+			entrants: entrant_arcs(data.entrants),
+			matches: data.matches,
+			len_rounds: data.len_rounds,
+			points: data.points,
+			tiebreakers: data.tiebreakers,
+			battle_system: B::default(),
+			rng: StdRng::from_entropy(),
+		})
+	}
+}
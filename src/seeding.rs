@@ -0,0 +1,63 @@
+use crate::types::*;
+use rand::RngCore;
+
+/// Strategies for placing entrants into a [`Tournament`](struct.Tournament.html)'s bracket, passed to [`Tournament::new_seeded()`](struct.Tournament.html#method.new_seeded).
+///
+/// Every strategy but [`Ordered`](#variant.Ordered) gives each entrant a seed. For [`Standard`](#variant.Standard) and [`Snake`](#variant.Snake) seeding, the order of the entrants `Vec` is their seed: the first entrant is seed 1, the second is seed 2, and so on. The bracket is padded out to the next power of two, and byes go to the top seeds.
+pub enum Seeding<'a> {
+	/// Entrants are placed in the bracket in the order given, as per [`Tournament::new()`](struct.Tournament.html#method.new).
+	Ordered,
+	/// Standard power-of-two seeding, where the sum of the seeds in every round is constant if the higher seed always wins: 1 vs 16, 8 vs 9, 4 vs 13 and so on. Seeds 1 and 2 can't meet before the final.
+	Standard,
+	/// Seeds are paired across the first round in a serpentine, with the top half of the seeds in order on side `A`, and the bottom half in reverse order on side `B`: 1 vs 16, 2 vs 15, 3 vs 14 and so on. Those rounds are dealt out to the two halves of the bracket in a serpentine too, with seed 1's round in the top half, 2's and 3's in the bottom half, 4's and 5's in the top half and so on, and each half keeps its rounds in seed order. Seeds 1 and 2 can't meet before the final.
+	Snake,
+	/// Entrants are shuffled with the supplied random number generator into a random seed order, then placed in the bracket as per [`Standard`](#variant.Standard).
+	Random(&'a mut dyn RngCore),
+	/// The supplied [`EntrantId`](struct.EntrantId.html)s are the seed order, starting from seed 1, and entrants are placed in the bracket as per [`Standard`](#variant.Standard). Every entrant must appear exactly once.
+	Custom(Vec<EntrantId>),
+}
+
+/// The seeds of a power-of-two bracket of `size` slots, in bracket order.
+fn standard_order(size: usize) -> Vec<usize> {
+	let mut order = vec![1];
+	while order.len() < size {
+		let sum = order.len() * 2 + 1;
+		order = order
+			.iter()
+			.flat_map(|seed| vec![*seed, sum - seed])
+			.collect();
+	}
+	order
+}
+
+/// The seeds of a power-of-two bracket of `size` slots, in snake order.
+fn snake_order(size: usize) -> Vec<usize> {
+	let mut top = vec![];
+	let mut bottom = vec![];
+	for seed in 1..=size / 2 {
+		let half = if seed / 2 % 2 == 0 {
+			&mut top
+		} else {
+			&mut bottom
+		};
+		half.extend(vec![seed, size + 1 - seed]);
+	}
+	top.extend(bottom);
+	top
+}
+
+/// Bracket slots for `seeds`, in seed order starting from seed 1. `None` slots are byes.
+pub(crate) fn seeded_slots(
+	seeding: &Seeding,
+	seeds: &[EntrantId],
+) -> Vec<Option<EntrantId>> {
+	let size = seeds.len().next_power_of_two();
+	let order = match seeding {
+		Seeding::Snake if size > 1 => snake_order(size),
+		_ => standard_order(size),
+	};
+	order
+		.into_iter()
+		.map(|seed| seeds.get(seed - 1).copied())
+		.collect()
+}
@@ -0,0 +1,149 @@
+use crate::league::*;
+use crate::types::*;
+use petgraph::{graph::NodeIndex, Graph};
+use serde::ser::{Error as _, SerializeSeq};
+use serde::{Serialize, Serializer};
+use std::fmt::{Debug, Display};
+use std::sync::{Arc, RwLock};
+
+/// The version of the schema used when serializing tournaments. Requires the `serde` feature.
+///
+/// [`Tournament`](struct.Tournament.html), [`DoubleEliminationTournament`](struct.DoubleEliminationTournament.html), [`RoundRobin`](struct.RoundRobin.html) and [`Swiss`](struct.Swiss.html) implement `Serialize` and `Deserialize` as a map, so that half-played tournaments can be saved and loaded again later. The battle system and random number generator aren't saved: the battle system is created with `B::default()` when loading, and the generator is seeded from system entropy. Every format shares these fields:
+/// - **`version`** - The schema version the tournament was written with. Deserializing fails if it's newer than `SCHEMA_VERSION`.
+/// - **`entrants`** - Every entrant `E`, in [`EntrantId`](struct.EntrantId.html) order.
+///
+/// Bracket formats add:
+/// - **`graph`** - The internal [graph](struct.Tournament.html#method.graph), in petgraph's serialized form: a map of `nodes` ([`TournamentNode`](enum.TournamentNode.html)s), `node_holes`, `edge_property` and `edges` (`[source, target, `[`TournamentEdge`](enum.TournamentEdge.html)`]` triples).
+/// - **`grand_finals`** - The index of the root round in `nodes`.
+/// - **`third_place`** - The index of the [third place playoff](struct.Tournament.html#method.with_third_place_playoff) in `nodes`, or `null` if there isn't one, for a [`Tournament`](struct.Tournament.html) only. Added in version 4, and `null` when missing.
+/// - **`withdrawn`** - The [`EntrantId`](struct.EntrantId.html)s of every [withdrawn](struct.Tournament.html#method.withdraw) entrant. Added in version 2, and empty when missing.
+/// - **`best_of`** - The lengths of [best-of-N series](struct.Tournament.html#method.set_best_of), for a [`Tournament`](struct.Tournament.html) only: a map of the `default` length, `depths` (`[depth, length]` pairs) and `rounds` (`[index in nodes, length]` pairs). Added in version 3, and every round is a single game when missing. Rounds played as a series keep every game in their `series`.
+/// - **`winners_finals`**, **`losers_finals`** and **`bracket_reset`** - Indices of those rounds in `nodes`, for a [`DoubleEliminationTournament`](struct.DoubleEliminationTournament.html) only. `bracket_reset` may be `null`.
+///
+/// League formats add:
+/// - **`matches`** - Every [`LeagueMatch`](struct.LeagueMatch.html), in schedule order.
+/// - **`len_rounds`** - The number of rounds in the schedule.
+/// - **`points`** and **`tiebreakers`** - The [`PointSystem`](struct.PointSystem.html) and [`LeagueTiebreaker`](enum.LeagueTiebreaker.html)s used by the standings table.
+/// - **`byes`** and **`rounds_played`** - The entrants given a bye each round, and the number of rounds played so far, for a [`Swiss`](struct.Swiss.html) tournament only.
+///
+/// # Example
+/// A single-elimination tournament between three `u32`s, with one round played, as JSON:
+/// ```json
+/// {
+/// 	"version": 4,
+/// 	"entrants": [3, 1, 2],
+/// 	"graph": {
+/// 		"nodes": [
+/// 			{ "Round": "Incomplete" },
+/// 			{ "Round": { "Complete": { "result": "A", "metadata": "2 beat 1", "decision": "Played", "series": [] } } },
+/// 			{ "Entrant": 0 },
+/// 			{ "Entrant": 1 },
+/// 			{ "Entrant": 2 }
+/// 		],
+/// 		"node_holes": [],
+/// 		"edge_property": "directed",
+/// 		"edges": [[0, 1, "A"], [0, 2, "B"], [1, 3, "A"], [1, 4, "B"]]
+/// 	},
+/// 	"grand_finals": 0,
+/// 	"third_place": null,
+/// 	"withdrawn": [],
+/// 	"best_of": { "default": 1, "depths": [], "rounds": [] }
+/// }
+/// ```
+pub const SCHEMA_VERSION: u32 = 4;
+
+/// Serializes a list of entrants as a sequence of `E`, reading each one through its lock.
+pub(crate) struct EntrantsRef<'a, E>(pub &'a [Arc<RwLock<E>>]);
+impl<'a, E: Serialize> Serialize for EntrantsRef<'a, E> {
+	fn serialize<S: Serializer>(
+		&self,
+		serializer: S,
+	) -> std::result::Result<S::Ok, S::Error> {
+		let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+		for entrant in self.0 {
+			let entrant = entrant
+				.read()
+				.map_err(|_| S::Error::custom("entrant lock is poisoned"))?;
+			seq.serialize_element(&*entrant)?;
+		}
+		seq.end()
+	}
+}
+
+/// Wraps deserialized entrants in `Arc<RwLock<E>>`s.
+pub(crate) fn entrant_arcs<E>(entrants: Vec<E>) -> Vec<Arc<RwLock<E>>> {
+	entrants
+		.into_iter()
+		.map(|entrant| Arc::new(RwLock::new(entrant)))
+		.collect()
+}
+
+/// Fails if a tournament was written with a newer schema than this version of the crate understands.
+pub(crate) fn check_version<Er: serde::de::Error>(
+	version: u32,
+) -> std::result::Result<(), Er> {
+	if version > SCHEMA_VERSION {
+		Err(Er::custom(format!(
+			"unsupported schema version {}, expected at most {}",
+			version, SCHEMA_VERSION
+		)))
+	} else {
+		Ok(())
+	}
+}
+
+/// Fails if any of a deserialized bracket's `roots` are missing from its graph, or any of its entrant nodes or `withdrawn` entrants don't belong to one of `len_entrants` entrants.
+pub(crate) fn check_bracket<
+	M: Debug + Display + Clone + Default,
+	Er: serde::de::Error,
+>(
+	graph: &Graph<TournamentNode<M>, TournamentEdge>,
+	roots: &[NodeIndex],
+	withdrawn: &[EntrantId],
+	len_entrants: usize,
+) -> std::result::Result<(), Er> {
+	if let Some(root) = roots
+		.iter()
+		.find(|root| graph.node_weight(**root).is_none())
+	{
+		return Err(Er::custom(format!(
+			"round {} is missing from the graph",
+			root.index()
+		)));
+	}
+	check_entrants(
+		graph
+			.node_indices()
+			.filter_map(|i| graph[i].entrant().copied())
+			.chain(withdrawn.iter().copied()),
+		len_entrants,
+	)
+}
+
+/// Fails if any of a deserialized league's matches or byes don't belong to one of `len_entrants` entrants.
+pub(crate) fn check_matches<
+	M: Debug + Display + Clone + Default,
+	Er: serde::de::Error,
+>(
+	matches: &[LeagueMatch<M>],
+	byes: &[EntrantId],
+	len_entrants: usize,
+) -> std::result::Result<(), Er> {
+	check_entrants(
+		matches
+			.iter()
+			.flat_map(|m| vec![m.a, m.b])
+			.chain(byes.iter().copied()),
+		len_entrants,
+	)
+}
+
+fn check_entrants<Er: serde::de::Error>(
+	ids: impl Iterator<Item = EntrantId>,
+	len_entrants: usize,
+) -> std::result::Result<(), Er> {
+	match ids.into_iter().find(|eid| eid.0 >= len_entrants) {
+		Some(eid) => Err(Er::custom(format!("{} doesn't exist", eid))),
+		None => Ok(()),
+	}
+}
@@ -0,0 +1,101 @@
+use crate::types::*;
+use petgraph::graph::NodeIndex;
+use rand::RngCore;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+use std::fmt::{Debug, Display};
+use std::sync::{Arc, RwLock};
+
+/// How many games each round of a [`Tournament`](struct.Tournament.html) is played over, as set by [`Tournament::set_best_of()`](struct.Tournament.html#method.set_best_of) and similar. A round's own length takes priority over the length of its depth, which takes priority over the default.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub(crate) struct SeriesLengths {
+	pub default: u32,
+	pub depths: Vec<(usize, u32)>,
+	pub rounds: Vec<(NodeIndex, u32)>,
+}
+impl Default for SeriesLengths {
+	fn default() -> Self {
+		Self {
+			default: 1,
+			depths: vec![],
+			rounds: vec![],
+		}
+	}
+}
+impl SeriesLengths {
+	/// The length of round `id`, which is `depth` rounds below the grand finals.
+	pub fn get(&self, id: NodeIndex, depth: usize) -> u32 {
+		find(&self.rounds, id)
+			.or_else(|| find(&self.depths, depth))
+			.unwrap_or(self.default)
+	}
+
+	/// Fails unless `best_of` is odd, so that one side always wins a majority of the games.
+	pub fn check(best_of: u32) -> Result<u32> {
+		if best_of % 2 == 1 {
+			Ok(best_of)
+		} else {
+			Err(TournamentError::InvalidBestOf(best_of))
+		}
+	}
+
+	/// Replaces the length stored under `key`, or adds it.
+	pub fn set<K: PartialEq>(lengths: &mut Vec<(K, u32)>, key: K, best_of: u32) {
+		lengths.retain(|(k, _)| *k != key);
+		lengths.push((key, best_of));
+	}
+}
+
+/// The length stored under `key`, if there is one.
+fn find<K: PartialEq>(lengths: &[(K, u32)], key: K) -> Option<u32> {
+	lengths.iter().find(|(k, _)| *k == key).map(|(_, n)| *n)
+}
+
+/// Plays a best-of-`best_of` series between `a` and `b`, calling `on_tie` whenever a game is tied. Returns the winner of the series, the metadata of the deciding game, and every game played, which is empty for a single game.
+pub(crate) fn play_series<
+	E: Debug + Display + Clone,
+	M: Debug + Display + Clone + Default,
+	B: BattleSystem<E, M>,
+>(
+	battle_system: &mut B,
+	a: &Arc<RwLock<E>>,
+	b: &Arc<RwLock<E>>,
+	rng: &mut dyn RngCore,
+	best_of: u32,
+	mut on_tie: impl FnMut(),
+) -> std::result::Result<
+	(TournamentRoundResult, M, Vec<SeriesGame<M>>),
+	BattleError,
+> {
+	let to_win = best_of / 2 + 1;
+	let (mut wins_a, mut wins_b) = (0, 0);
+	let mut series = vec![];
+	loop {
+		let (result, metadata) =
+			match battle_system.battle(a.clone(), b.clone(), rng) {
+				BattleResult::Solved(result, metadata) => (result, metadata),
+				BattleResult::Tie => {
+					on_tie();
+					battle_system.tiebreaker(a.clone(), b.clone(), rng)?
+				}
+				BattleResult::Failed(e) => return Err(e),
+			};
+		match result {
+			TournamentRoundResult::A => wins_a += 1,
+			TournamentRoundResult::B => wins_b += 1,
+		}
+		if wins_a < to_win && wins_b < to_win {
+			series.push(SeriesGame { result, metadata });
+			continue;
+		}
+		if best_of > 1 {
+			series.push(SeriesGame {
+				result,
+				metadata: metadata.clone(),
+			});
+		}
+		return Ok((result, metadata, series));
+	}
+}
@@ -0,0 +1,237 @@
+use crate::tournament::*;
+use crate::types::*;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+use std::fmt::{Debug, Display};
+
+/// The odds of a single entrant, as estimated by [`Tournament::simulate()`](struct.Tournament.html#method.simulate).
+#[derive(Debug, Clone, PartialEq)]
+pub struct EntrantOdds {
+	/// The entrant these odds belong to.
+	pub entrant: EntrantId,
+	/// The probability of reaching each layer of rounds, by distance from the grand finals. `reached[0]` is the probability of playing in the grand finals, `reached[1]` in the semifinals, and so on. Layers skipped with a bye count as reached.
+	pub reached: Vec<f64>,
+	/// The probability of winning the tournament.
+	pub champion: f64,
+}
+
+/// The results of a Monte Carlo simulation of a tournament, returned by [`Tournament::simulate()`](struct.Tournament.html#method.simulate) and [`Tournament::simulate_parallel()`](struct.Tournament.html#method.simulate_parallel).
+#[derive(Debug, Clone, PartialEq)]
+pub struct Simulation {
+	runs: usize,
+	odds: Vec<EntrantOdds>,
+}
+impl Simulation {
+	/// Get the number of times the tournament was played out.
+	pub fn runs(&self) -> usize {
+		self.runs
+	}
+
+	/// Get a ref to the [`EntrantOdds`](struct.EntrantOdds.html) of every entrant, in order of [`EntrantId`](struct.EntrantId.html).
+	pub fn odds(&self) -> &Vec<EntrantOdds> {
+		&self.odds
+	}
+
+	/// Get the [`EntrantOdds`](struct.EntrantOdds.html) of a single entrant. Returns `None` if the entrant isn't in the tournament.
+	pub fn entrant_odds(&self, id: EntrantId) -> Option<&EntrantOdds> {
+		self.odds.get(id.0)
+	}
+}
+
+/// How often every entrant reached each layer of rounds, and won, over a number of runs.
+struct Tally {
+	runs: usize,
+	reached: Vec<Vec<usize>>,
+	champion: Vec<usize>,
+}
+impl Tally {
+	fn new(entrants: usize, layers: usize) -> Self {
+		Self {
+			runs: 0,
+			reached: vec![vec![0; layers]; entrants],
+			champion: vec![0; entrants],
+		}
+	}
+
+	fn merge(&mut self, other: Tally) {
+		self.runs += other.runs;
+		for (mine, theirs) in self.reached.iter_mut().zip(other.reached) {
+			for (m, t) in mine.iter_mut().zip(theirs) {
+				*m += t;
+			}
+		}
+		for (m, t) in self.champion.iter_mut().zip(other.champion) {
+			*m += t;
+		}
+	}
+
+	fn into_simulation(self) -> Simulation {
+		let runs = self.runs as f64;
+		Simulation {
+			runs: self.runs,
+			odds: self
+				.reached
+				.into_iter()
+				.zip(self.champion)
+				.enumerate()
+				.map(|(i, (reached, champion))| EntrantOdds {
+					entrant: EntrantId(i),
+					reached: reached.into_iter().map(|c| c as f64 / runs).collect(),
+					champion: champion as f64 / runs,
+				})
+				.collect(),
+		}
+	}
+}
+
+impl<
+		E: Debug + Display + Clone,
+		M: Debug + Display + Clone + Default,
+		B: BattleSystem<E, M> + Clone,
+	> Tournament<E, M, B>
+{
+	/// Play out the rest of the tournament `runs` times, and estimate how likely every entrant is to reach each round, and to win. Meant for battle systems that make use of randomness.
+	///
+	/// Every run is played on fresh clones of the entrants and of the battle system, as they are now, so the tournament itself is left untouched. Rounds that have already been solved keep their results. Each run gets its own random number generator, seeded from `seed`, so a simulation with the same seed always gives the same results.
+	///
+	/// # Example
+	/// ```
+	/// # use ultra_tournament::*;
+	/// # use rand::{Rng, RngCore};
+	/// # use std::sync::{Arc, RwLock};
+	/// # #[derive(Debug, Clone, Default)]
+	/// # struct MyMetadata;
+	/// # impl MyMetadata {
+	/// # 	fn new() -> Self {
+	/// # 		Self
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Display for MyMetadata {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "My Metadata")
+	/// # 	}
+	/// # }
+	/// /// The larger number wins more often.
+	/// #[derive(Clone, Default)]
+	/// struct DiceBattleSystem;
+	/// impl BattleSystem<u32, MyMetadata> for DiceBattleSystem {
+	/// 	fn battle(&mut self, a: Arc<RwLock<u32>>, b: Arc<RwLock<u32>>, rng: &mut dyn RngCore) -> BattleResult<MyMetadata> {
+	/// 		let a = rng.gen_range(0, *a.read().unwrap());
+	/// 		let b = rng.gen_range(0, *b.read().unwrap());
+	/// 		if a >= b {
+	/// 			BattleResult::Solved(TournamentRoundResult::A, MyMetadata::new())
+	/// 		} else {
+	/// 			BattleResult::Solved(TournamentRoundResult::B, MyMetadata::new())
+	/// 		}
+	/// 	}
+	/// 	fn tiebreaker(&mut self, _: Arc<RwLock<u32>>, _: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> TiebreakerResult<MyMetadata> {
+	/// 		Ok((TournamentRoundResult::A, MyMetadata::new()))
+	/// 	}
+	/// }
+	/// # fn main() {
+	/// let t = Tournament::<u32, MyMetadata, DiceBattleSystem>::new(vec![6, 8, 10, 12]).unwrap();
+	/// let simulation = t.simulate(1000, 42).unwrap();
+	///
+	/// let d12 = simulation.entrant_odds(EntrantId(3)).unwrap();
+	/// let d6 = simulation.entrant_odds(EntrantId(0)).unwrap();
+	/// assert!(d12.champion > d6.champion);
+	/// # }
+	/// ```
+	pub fn simulate(&self, runs: usize, seed: u64) -> Result<Simulation> {
+		let seeds = run_seeds(runs, seed)?;
+		Ok(self.tally(&seeds)?.into_simulation())
+	}
+
+	/// Plays out one fork of the tournament per seed, and counts how far every entrant got.
+	fn tally(&self, seeds: &[u64]) -> Result<Tally> {
+		let layers = self.layers()?.len();
+		let mut tally = Tally::new(self.len_entrants(), layers);
+		for seed in seeds {
+			let mut t = self
+				.fork(self.battle_system().clone(), StdRng::seed_from_u64(*seed))?;
+			t.solve()?;
+
+			// The shallowest layer each entrant played a round in.
+			let mut best = vec![layers; t.len_entrants()];
+			for (depth, layer) in t.layers()?.iter().enumerate() {
+				for id in layer {
+					let (a, b) = t.child_nodes(*id)?;
+					for child in [a, b].iter() {
+						let eid = t
+							.winner(*child)?
+							.ok_or(TournamentError::MalformedRound(*child))?;
+						best[eid.0] = best[eid.0].min(depth);
+					}
+				}
+			}
+			for (eid, depth) in best.into_iter().enumerate() {
+				for count in tally.reached[eid].iter_mut().skip(depth) {
+					*count += 1;
+				}
+			}
+			let champion = t
+				.winner(*t.grand_finals())?
+				.ok_or(TournamentError::MalformedRound(*t.grand_finals()))?;
+			tally.champion[champion.0] += 1;
+			tally.runs += 1;
+		}
+		Ok(tally)
+	}
+}
+
+impl<
+		E: Debug + Display + Clone + Send + Sync,
+		M: Debug + Display + Clone + Default + Send,
+		B: BattleSystem<E, M> + Clone + Send,
+	> Tournament<E, M, B>
+{
+	/// Identical to [`simulate()`](#method.simulate), but spreads the runs over `threads` threads. Passing `0` uses as many threads as there are CPUs available. Gives the same results as `simulate()` with the same seed, no matter the number of threads.
+	pub fn simulate_parallel(
+		&self,
+		runs: usize,
+		seed: u64,
+		threads: usize,
+	) -> Result<Simulation> {
+		let seeds = run_seeds(runs, seed)?;
+		let threads = match threads {
+			0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+			n => n,
+		};
+		let chunk_size = runs.div_ceil(threads);
+
+		// Each thread gets its own copy to fork runs from, so the tournament doesn't need to be `Sync`.
+		let templates = seeds
+			.chunks(chunk_size)
+			.map(|chunk| {
+				let template = self
+					.fork(self.battle_system().clone(), StdRng::seed_from_u64(seed))?;
+				Ok((template, chunk))
+			})
+			.collect::<Result<Vec<_>>>()?;
+
+		let mut tally = Tally::new(self.len_entrants(), self.layers()?.len());
+		std::thread::scope(|scope| {
+			let handles: Vec<_> = templates
+				.into_iter()
+				.map(|(template, chunk)| scope.spawn(move || template.tally(chunk)))
+				.collect();
+			for handle in handles {
+				// Pass on panics in the battle system, as if the runs were played on this thread.
+				let result = handle
+					.join()
+					.unwrap_or_else(|e| std::panic::resume_unwind(e));
+				tally.merge(result?);
+			}
+			Ok(tally.into_simulation())
+		})
+	}
+}
+
+/// The seed of every run of a simulation, drawn from a generator seeded with `seed`.
+fn run_seeds(runs: usize, seed: u64) -> Result<Vec<u64>> {
+	if runs == 0 {
+		return Err(TournamentError::NeedsAtLeastOneRun);
+	}
+	let mut rng = StdRng::seed_from_u64(seed);
+	Ok((0..runs).map(|_| rng.next_u64()).collect())
+}
@@ -0,0 +1,153 @@
+use crate::tournament::*;
+use crate::types::*;
+use petgraph::graph::NodeIndex;
+use std::fmt::{self, Debug, Display};
+
+/// An entrant's finishing place in a [`Tournament`](struct.Tournament.html), as returned by [`Tournament::standings()`](struct.Tournament.html#method.standings).
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BracketStanding {
+	/// The entrant this standing belongs to.
+	pub entrant: EntrantId,
+	/// The best place the entrant shares, starting from `1` for the champion.
+	pub place: usize,
+	/// The worst place the entrant shares. The same as `place` unless the entrant is tied with others, such as the losers of both semifinals sharing `3` to `4`.
+	pub lowest_place: usize,
+	/// The last round the entrant lost, or `None` if they haven't lost one yet.
+	pub eliminated_in: Option<NodeIndex>,
+}
+impl BracketStanding {
+	/// Whether the entrant shares their place with anyone else.
+	pub fn is_tied(&self) -> bool {
+		self.place != self.lowest_place
+	}
+}
+impl Display for BracketStanding {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		if self.is_tied() {
+			write!(
+				f,
+				"{}: {}-{}",
+				self.entrant,
+				ordinal(self.place),
+				ordinal(self.lowest_place)
+			)
+		} else {
+			write!(f, "{}: {}", self.entrant, ordinal(self.place))
+		}
+	}
+}
+
+/// `1st`, `2nd`, `3rd`, `4th` and so on.
+fn ordinal(n: usize) -> String {
+	let suffix = match (n % 10, n % 100) {
+		(_, 11..=13) => "th",
+		(1, _) => "st",
+		(2, _) => "nd",
+		(3, _) => "rd",
+		_ => "th",
+	};
+	format!("{}{}", n, suffix)
+}
+
+impl<
+		E: Debug + Display + Clone,
+		M: Debug + Display + Clone + Default,
+		B: BattleSystem<E, M>,
+	> Tournament<E, M, B>
+{
+	/// Get the finishing place of every entrant, sorted from first to last place, then by [`EntrantId`](struct.EntrantId.html).
+	///
+	/// Entrants are ranked by how close to the grand finals the round they lost was. The champion is `1st` and the loser of the grand finals `2nd`. Everyone who lost a round the same distance from the grand finals shares a range of places: the semifinal losers tie for `3rd-4th`, the quarterfinal losers for `5th-8th`, and so on. In a bracket with byes, a layer of rounds can hold fewer matches than a full bracket would, so the range is only as wide as the number of entrants who actually lost there.
+	///
+	/// If there's a [third place playoff](#method.with_third_place_playoff) and it has been solved, its winner is `3rd` and its loser `4th`.
+	///
+	/// Entrants who haven't lost a round yet share the top places until they do, so before anything is solved every entrant ties for `1st` to last.
+	///
+	/// # Example
+	/// ```
+	/// # use ultra_tournament::*;
+	/// # use rand::RngCore;
+	/// # use std::sync::{Arc, RwLock};
+	/// # #[derive(Debug, Clone, Default)]
+	/// # struct MyMetadata;
+	/// # impl MyMetadata {
+	/// # 	fn new() -> Self {
+	/// # 		Self
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Display for MyMetadata {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "My Metadata")
+	/// # 	}
+	/// # }
+	/// # #[derive(Clone, Default)]
+	/// # struct MyBattleSystem;
+	/// # impl BattleSystem<u32, MyMetadata> for MyBattleSystem {
+	/// # 	fn battle(&mut self, a: Arc<RwLock<u32>>, b: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> BattleResult<MyMetadata> {
+	/// # 		if *a.read().unwrap() >= *b.read().unwrap() {
+	/// # 			BattleResult::Solved(TournamentRoundResult::A, MyMetadata::new())
+	/// # 		} else {
+	/// # 			BattleResult::Solved(TournamentRoundResult::B, MyMetadata::new())
+	/// # 		}
+	/// # 	}
+	/// # 	fn tiebreaker(&mut self, _: Arc<RwLock<u32>>, _: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> TiebreakerResult<MyMetadata> {
+	/// # 		Ok((TournamentRoundResult::A, MyMetadata::new()))
+	/// # 	}
+	/// # }
+	/// # fn main() {
+	/// let mut t = Tournament::<u32, MyMetadata, MyBattleSystem>::new(vec![
+	/// 	1, 2, 3, 4, 5, 6, 7, 8,
+	/// ])
+	/// .unwrap();
+	/// t.solve().unwrap();
+	///
+	/// let standings = t.standings().unwrap();
+	/// assert_eq!(standings[0].entrant, EntrantId(7));
+	/// assert_eq!(standings[0].to_string(), "Entrant #7: 1st");
+	/// assert_eq!(standings[1].place, 2);
+	/// assert!(standings[2..4].iter().all(|s| (s.place, s.lowest_place) == (3, 4)));
+	/// assert!(standings[4..].iter().all(|s| s.to_string().ends_with("5th-8th")));
+	/// # }
+	/// ```
+	pub fn standings(&self) -> Result<Vec<BracketStanding>> {
+		// Smaller ranks finish higher. Entrants still in the running rank 0, and the loser of a round `depth` layers below the grand finals ranks `depth + 1`. The loser of the third place playoff ranks just below its winner.
+		let mut ranks = vec![((0, 0), None); self.len_entrants()];
+		for (depth, layer) in self.layers()?.iter().enumerate() {
+			for id in layer.iter() {
+				if let Some(loser) = self.loser(*id)? {
+					ranks[loser.0] = ((depth + 1, 0), Some(*id));
+				}
+			}
+		}
+		if let Some(third_place) = self.third_place() {
+			if let Some(loser) = self.loser(*third_place)? {
+				ranks[loser.0] = ((2, 1), Some(*third_place));
+			}
+		}
+
+		// Every run of entrants with the same rank shares the places it spans once sorted.
+		let mut order: Vec<usize> = (0..ranks.len()).collect();
+		order.sort_by_key(|i| (ranks[*i].0, *i));
+		let mut standings = Vec::with_capacity(order.len());
+		let mut start = 0;
+		while start < order.len() {
+			let rank = ranks[order[start]].0;
+			let end = start
+				+ order[start..]
+					.iter()
+					.take_while(|i| ranks[**i].0 == rank)
+					.count();
+			for i in order[start..end].iter() {
+				standings.push(BracketStanding {
+					entrant: EntrantId(*i),
+					place: start + 1,
+					lowest_place: end,
+					eliminated_in: ranks[*i].1,
+				});
+			}
+			start = end;
+		}
+		Ok(standings)
+	}
+}
@@ -0,0 +1,202 @@
+use crate::tournament::*;
+use crate::types::*;
+use petgraph::graph::NodeIndex;
+use std::collections::HashMap;
+use std::fmt::{Debug, Display};
+
+/// Height of a single slot of the bracket, in pixels.
+const SLOT_HEIGHT: usize = 24;
+/// Vertical space between neighbouring entrants in the first column.
+const ROW_GAP: usize = 8;
+/// Horizontal space between columns, where the connector lines are drawn.
+const COLUMN_GAP: usize = 32;
+/// Space around the whole bracket, and between a slot's edge and its text.
+const PADDING: usize = 8;
+/// Rough width of a single character at the default font size, used to size slots to fit the longest name.
+const CHAR_WIDTH: usize = 7;
+/// Slots are never narrower than this.
+const MIN_SLOT_WIDTH: usize = 96;
+
+impl<
+		E: Debug + Display + Clone,
+		M: Debug + Display + Clone + Default,
+		B: BattleSystem<E, M>,
+	> Tournament<E, M, B>
+{
+	/// Render the bracket as a standalone SVG image, laid out left-to-right with one column per round, from the opening rounds to the grand finals.
+	///
+	/// - Every slot shows the entrant that reached it, using the entrant's `Display` text. Slots of incomplete rounds are left empty and dashed.
+	/// - The winner of each round is drawn in bold, as is the champion.
+	/// - Round metadata is shown as a tooltip when hovering over the round's slot.
+	/// - Entrants with a bye are drawn in the first column with everybody else, and connected straight through to their first round.
+	/// - The [third place playoff](#method.with_third_place_playoff), if there is one, is drawn below the bracket, with the losers of the semifinals in the semifinals' column.
+	///
+	/// # Example
+	/// ```
+	/// # use ultra_tournament::*;
+	/// # use rand::RngCore;
+	/// # use std::sync::{Arc, RwLock};
+	/// # #[derive(Debug, Clone, Default)]
+	/// # struct MyMetadata;
+	/// # impl MyMetadata {
+	/// # 	fn new() -> Self {
+	/// # 		Self
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Display for MyMetadata {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "My Metadata")
+	/// # 	}
+	/// # }
+	/// # #[derive(Clone, Default)]
+	/// # struct MyBattleSystem;
+	/// # impl BattleSystem<u32, MyMetadata> for MyBattleSystem {
+	/// # 	fn battle(&mut self, a: Arc<RwLock<u32>>, b: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> BattleResult<MyMetadata> {
+	/// # 		if *a.read().unwrap() >= *b.read().unwrap() {
+	/// # 			BattleResult::Solved(TournamentRoundResult::A, MyMetadata::new())
+	/// # 		} else {
+	/// # 			BattleResult::Solved(TournamentRoundResult::B, MyMetadata::new())
+	/// # 		}
+	/// # 	}
+	/// # 	fn tiebreaker(&mut self, _: Arc<RwLock<u32>>, _: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> TiebreakerResult<MyMetadata> {
+	/// # 		Ok((TournamentRoundResult::A, MyMetadata::new()))
+	/// # 	}
+	/// # }
+	/// # fn main() {
+	/// let mut t =
+	/// 	Tournament::<u32, MyMetadata, MyBattleSystem>::new(vec![1, 2, 3, 4, 5]).unwrap();
+	/// t.solve().unwrap();
+	///
+	/// let svg = t.to_svg().unwrap();
+	/// std::fs::write("bracket.svg", svg).unwrap();
+	/// # std::fs::remove_file("bracket.svg").unwrap();
+	/// # }
+	/// ```
+	pub fn to_svg(&self) -> Result<String> {
+		let mut slots = vec![];
+		let mut rows = 0;
+		self.place_slot(*self.grand_finals(), None, 0, &mut rows, &mut slots)?;
+		if let Some(third_place) = self.third_place() {
+			// Leave an empty row between the bracket and the playoff.
+			rows += 1;
+			self.place_slot(*third_place, None, 0, &mut rows, &mut slots)?;
+		}
+
+		let mut names = vec![];
+		for slot in slots.iter() {
+			names.push(match self.winner(slot.id)? {
+				Some(eid) => Some((eid, self.entrant_name(eid)?)),
+				None => None,
+			});
+		}
+		let width = names
+			.iter()
+			.flatten()
+			.map(|(_, name)| name.chars().count() * CHAR_WIDTH + PADDING * 2)
+			.fold(MIN_SLOT_WIDTH, usize::max);
+		let columns = slots.iter().map(|slot| slot.depth).max().unwrap_or(0);
+		// Entrants always go in the first column, and rounds by distance from the grand finals.
+		let column = |slot: &Slot| match self.graph()[slot.id] {
+			TournamentNode::Entrant(_) => 0,
+			_ => columns - slot.depth,
+		};
+		let x = |slot: &Slot| PADDING + column(slot) * (width + COLUMN_GAP);
+		let y = |slot: &Slot| {
+			PADDING + (slot.row * (SLOT_HEIGHT + ROW_GAP) as f64).round() as usize
+		};
+		let svg_width = PADDING * 2 + (columns + 1) * width + columns * COLUMN_GAP;
+		let svg_height =
+			PADDING * 2 + rows * SLOT_HEIGHT + rows.saturating_sub(1) * ROW_GAP;
+
+		let mut lines = vec![
+			format!(
+				"<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{0}\" height=\"{1}\" viewBox=\"0 0 {0} {1}\" font-family=\"sans-serif\" font-size=\"12\">",
+				svg_width, svg_height
+			),
+			"\t<g fill=\"none\" stroke=\"#888888\">".to_string(),
+		];
+		let by_id: HashMap<NodeIndex, &Slot> =
+			slots.iter().map(|slot| (slot.id, slot)).collect();
+		for slot in slots.iter() {
+			if let Some(parent) = slot.parent.map(|p| by_id[&p]) {
+				let (x1, y1) = (x(slot) + width, y(slot) + SLOT_HEIGHT / 2);
+				let (x2, y2) = (x(parent), y(parent) + SLOT_HEIGHT / 2);
+				lines.push(format!(
+					"\t\t<path d=\"M{} {} H{} V{} H{}\"/>",
+					x1,
+					y1,
+					x2 - COLUMN_GAP / 2,
+					y2,
+					x2
+				));
+			}
+		}
+		lines.push("\t</g>".to_string());
+
+		for (slot, name) in slots.iter().zip(names.iter()) {
+			let node = &self.graph()[slot.id];
+			// A slot is bold when its entrant went on to win the round above it.
+			let advanced = match (slot.parent, name) {
+				(Some(parent), Some((eid, _))) => self.winner(parent)? == Some(*eid),
+				(None, Some(_)) => true,
+				_ => false,
+			};
+			lines.push("\t<g>".to_string());
+			if let Some(metadata) = node.metadata() {
+				lines.push(format!(
+					"\t\t<title>{}</title>",
+					escape_xml(&metadata.to_string())
+				));
+			}
+			lines.push(format!(
+				"\t\t<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"3\" fill=\"#ffffff\" stroke=\"#444444\"{}/>",
+				x(slot),
+				y(slot),
+				width,
+				SLOT_HEIGHT,
+				if name.is_some() {
+					""
+				} else {
+					" stroke-dasharray=\"4 2\""
+				}
+			));
+			if let Some((_, name)) = name {
+				lines.push(format!(
+					"\t\t<text x=\"{}\" y=\"{}\" dominant-baseline=\"central\"{}>{}</text>",
+					x(slot) + PADDING,
+					y(slot) + SLOT_HEIGHT / 2,
+					if advanced { " font-weight=\"bold\"" } else { "" },
+					escape_xml(name)
+				));
+			}
+			lines.push("\t</g>".to_string());
+		}
+		lines.push("</svg>".to_string());
+		Ok(lines.join("\n") + "\n")
+	}
+
+	/// The `Display` text of an entrant.
+	pub(crate) fn entrant_name(&self, eid: EntrantId) -> Result<String> {
+		let arc = self.entrant(eid);
+		let entrant = arc
+			.read()
+			.or(Err(TournamentError::EntrantLockPoisoned(eid)))?;
+		Ok(entrant.to_string())
+	}
+}
+
+/// Escapes text for use in XML and HTML, both inside elements and quoted attributes.
+pub(crate) fn escape_xml(text: &str) -> String {
+	let mut escaped = String::with_capacity(text.len());
+	for c in text.chars() {
+		match c {
+			'&' => escaped.push_str("&amp;"),
+			'<' => escaped.push_str("&lt;"),
+			'>' => escaped.push_str("&gt;"),
+			'"' => escaped.push_str("&quot;"),
+			'\'' => escaped.push_str("&#39;"),
+			_ => escaped.push(c),
+		}
+	}
+	escaped
+}
@@ -0,0 +1,354 @@
+use crate::league::*;
+#[cfg(feature = "serde")]
+use crate::serialization::*;
+use crate::types::*;
+use rand::rngs::StdRng;
+use rand::SeedableRng;
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::clone::Clone;
+use std::collections::HashSet;
+use std::default::Default;
+use std::fmt::{Debug, Display};
+use std::sync::{Arc, RwLock};
+
+/// Upper bound on the number of pairings tried while searching for a round without rematches, before rematches are allowed.
+const PAIRING_BUDGET: usize = 100_000;
+
+/// `Swiss<E, M, B>` creates a Swiss-system tournament, which runs a set number of rounds where entrants are paired against others with similar scores.
+///
+/// Entrants never play each other twice, unless there is no other way to pair a round. When there's an odd number of entrants, the lowest ranked entrant that hasn't had a bye yet sits out each round, and is awarded the points of a win.
+///
+/// As in a [`RoundRobin`](struct.RoundRobin.html), a [`BattleResult::Tie`](enum.BattleResult.html#variant.Tie) is recorded as a draw, and entrants are ranked in a [standings table](#method.standings). By default, entrants level on points are ordered by [`Buchholz`](enum.LeagueTiebreaker.html#variant.Buchholz), then [`SonnebornBerger`](enum.LeagueTiebreaker.html#variant.SonnebornBerger) score.
+///
+/// Type parameters are identical to those of [`Tournament`](struct.Tournament.html).
+#[derive(Debug)]
+pub struct Swiss<
+	E: Debug + Display + Clone,
+	M: Debug + Display + Clone + Default,
+	B: BattleSystem<E, M>,
+> {
+	entrants: Vec<Arc<RwLock<E>>>,
+	matches: Vec<LeagueMatch<M>>,
+	byes: Vec<EntrantId>,
+	len_rounds: usize,
+	rounds_played: usize,
+	points: PointSystem,
+	tiebreakers: Vec<LeagueTiebreaker>,
+	battle_system: B,
+	rng: StdRng,
+}
+
+impl<
+		E: Debug + Display + Clone,
+		M: Debug + Display + Clone + Default,
+		B: BattleSystem<E, M> + Default,
+	> Swiss<E, M, B>
+{
+	/// Create a new `Swiss` tournament from a `Vec<E>` of entrant structs, that runs for `rounds` rounds.
+	///
+	/// Requires at least two entrants. In the first round, entrants are paired in the `Vec<E>`'s order.
+	pub fn new(entrants: Vec<E>, rounds: usize) -> Result<Self> {
+		Self::new_with_battle_system(entrants, rounds, B::default())
+	}
+}
+
+impl<
+		E: Debug + Display + Clone,
+		M: Debug + Display + Clone + Default,
+		B: BattleSystem<E, M>,
+	> Swiss<E, M, B>
+{
+	/// Create a new `Swiss` tournament as per [`new()`](#method.new), where matches are solved with the supplied instance of the battle system.
+	pub fn new_with_battle_system(
+		entrants: Vec<E>,
+		rounds: usize,
+		battle_system: B,
+	) -> Result<Self> {
+		if entrants.len() < 2 {
+			return Err(TournamentError::NeedsAtLeastTwoEntrants);
+		}
+		if rounds == 0 {
+			return Err(TournamentError::NeedsAtLeastOneRound);
+		}
+
+		Ok(Swiss::<E, M, B> {
+			entrants: entrants
+				.into_iter()
+				.map(|entrant| Arc::new(RwLock::new(entrant)))
+				.collect(),
+			matches: vec![],
+			byes: vec![],
+			len_rounds: rounds,
+			rounds_played: 0,
+			points: PointSystem::default(),
+			tiebreakers: vec![
+				LeagueTiebreaker::Buchholz,
+				LeagueTiebreaker::SonnebornBerger,
+			],
+			battle_system,
+			rng: StdRng::from_entropy(),
+		})
+	}
+
+	/// Get the number of entrants in the tournament.
+	pub fn len_entrants(&self) -> usize {
+		self.entrants.len()
+	}
+
+	/// Get the number of rounds the tournament runs for.
+	pub fn len_rounds(&self) -> usize {
+		self.len_rounds
+	}
+
+	/// Get the number of rounds that have been paired and played.
+	pub fn rounds_played(&self) -> usize {
+		self.rounds_played
+	}
+
+	/// Get an `Arc<RwLock<E>>` encapsulating an entrant of specified [`EntrantId`](struct.EntrantId.html)
+	pub fn entrant(&self, id: EntrantId) -> Arc<RwLock<E>> {
+		self.entrants.get(id.0).unwrap().clone()
+	}
+
+	/// Get a ref to every [`LeagueMatch`](struct.LeagueMatch.html) played so far, in the order they were played.
+	pub fn matches(&self) -> &Vec<LeagueMatch<M>> {
+		&self.matches
+	}
+
+	/// Get a ref to the battle system used to solve matches.
+	pub fn battle_system(&self) -> &B {
+		&self.battle_system
+	}
+
+	/// Get a mutable ref to the battle system used to solve matches, to change its configuration between matches.
+	pub fn battle_system_mut(&mut self) -> &mut B {
+		&mut self.battle_system
+	}
+
+	/// Seed the random number generator passed to the battle system, so that solving the tournament is reproducible. By default, it's seeded from system entropy.
+	pub fn set_seed(&mut self, seed: u64) {
+		self.rng = StdRng::seed_from_u64(seed);
+	}
+
+	/// Replace the random number generator passed to the battle system.
+	pub fn set_rng(&mut self, rng: StdRng) {
+		self.rng = rng;
+	}
+
+	/// Get a ref to the entrants that received a bye, one per round played. Empty if there's an even number of entrants.
+	pub fn byes(&self) -> &Vec<EntrantId> {
+		&self.byes
+	}
+
+	/// Get the [`PointSystem`](struct.PointSystem.html) used by the standings table, and for pairing. Defaults to three points for a win and one for a draw.
+	pub fn points(&self) -> &PointSystem {
+		&self.points
+	}
+
+	/// Set the [`PointSystem`](struct.PointSystem.html) used by the standings table, and for pairing.
+	pub fn set_points(&mut self, points: PointSystem) {
+		self.points = points;
+	}
+
+	/// Get the [`LeagueTiebreaker`](enum.LeagueTiebreaker.html)s used to order entrants level on points.
+	pub fn tiebreakers(&self) -> &Vec<LeagueTiebreaker> {
+		&self.tiebreakers
+	}
+
+	/// Set the [`LeagueTiebreaker`](enum.LeagueTiebreaker.html)s used to order entrants level on points, in order of priority.
+	pub fn set_tiebreakers(&mut self, tiebreakers: Vec<LeagueTiebreaker>) {
+		self.tiebreakers = tiebreakers;
+	}
+
+	/// Plays every remaining round of the tournament.
+	pub fn solve(&mut self) -> Result<()> {
+		while self.solve_next_round()?.is_some() {}
+		Ok(())
+	}
+
+	/// Pairs and plays the next round of the tournament, returning its index. Returns `None` if every round has already been played.
+	///
+	/// If the battle system [fails](enum.BattleResult.html#variant.Failed) in any match, the whole round is discarded, including its bye, and [`TournamentError::MatchFailed`](enum.TournamentError.html#variant.MatchFailed) is returned with the index the match would have had in [`matches()`](#method.matches). Calling this again pairs the round from scratch.
+	pub fn solve_next_round(&mut self) -> Result<Option<usize>> {
+		if self.rounds_played >= self.len_rounds {
+			return Ok(None);
+		}
+		let round = self.rounds_played;
+
+		let mut order: Vec<EntrantId> =
+			self.standings().into_iter().map(|s| s.entrant).collect();
+
+		let len_byes = self.byes.len();
+		if order.len() % 2 == 1 {
+			let byes =
+				|eid: &EntrantId| self.byes.iter().filter(|b| *b == eid).count();
+			let fewest = order.iter().map(byes).min().unwrap_or(0);
+			let i = order
+				.iter()
+				.rposition(|eid| byes(eid) == fewest)
+				.ok_or(TournamentError::MalformedBracket)?;
+			self.byes.push(order.remove(i));
+		}
+
+		let played: HashSet<(EntrantId, EntrantId)> = self
+			.matches
+			.iter()
+			.map(|m| (m.a.min(m.b), m.a.max(m.b)))
+			.collect();
+		// Rematches are only allowed when there's no other way to pair the round.
+		let (mut budget, mut fallback_budget) = (PAIRING_BUDGET, PAIRING_BUDGET);
+		let pairs = pair(&order, &played, &mut budget)
+			.or_else(|| pair(&order, &HashSet::new(), &mut fallback_budget))
+			.ok_or(TournamentError::MalformedBracket)?;
+
+		let sides_a =
+			|eid: EntrantId| self.matches.iter().filter(|m| m.a == eid).count();
+		let mut new_matches: Vec<LeagueMatch<M>> = pairs
+			.into_iter()
+			.map(|(high, low)| {
+				// The higher ranked entrant plays on side `A`, unless they've done so more often.
+				let (a, b) = if sides_a(high) > sides_a(low) {
+					(low, high)
+				} else {
+					(high, low)
+				};
+				LeagueMatch {
+					a,
+					b,
+					round,
+					outcome: None,
+				}
+			})
+			.collect();
+		let first = self.matches.len();
+		for (i, m) in new_matches.iter_mut().enumerate() {
+			let played = play_match(
+				m,
+				first + i,
+				&self.entrants,
+				&mut self.battle_system,
+				&mut self.rng,
+			);
+			if let Err(e) = played {
+				self.byes.truncate(len_byes);
+				return Err(e);
+			}
+		}
+		self.matches.extend(new_matches);
+		self.rounds_played += 1;
+		Ok(Some(round))
+	}
+
+	/// Get the standings table of the tournament, from first to last place.
+	pub fn standings(&self) -> Vec<LeagueStanding> {
+		standings(
+			self.entrants.len(),
+			&self.matches,
+			&self.byes,
+			&self.points,
+			&self.tiebreakers,
+		)
+	}
+}
+
+/// The serialized form of a [`Swiss`](struct.Swiss.html), as documented on [`SCHEMA_VERSION`](constant.SCHEMA_VERSION.html).
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Swiss")]
+struct SwissData<Es, Ms> {
+	version: u32,
+	entrants: Es,
+	matches: Ms,
+	byes: Vec<EntrantId>,
+	len_rounds: usize,
+	rounds_played: usize,
+	points: PointSystem,
+	tiebreakers: Vec<LeagueTiebreaker>,
+}
+
+#[cfg(feature = "serde")]
+impl<
+		E: Debug + Display + Clone + Serialize,
+		M: Debug + Display + Clone + Default + Serialize,
+		B: BattleSystem<E, M>,
+	> Serialize for Swiss<E, M, B>
+{
+	fn serialize<S: Serializer>(
+		&self,
+		serializer: S,
+	) -> std::result::Result<S::Ok, S::Error> {
+		SwissData {
+			version: SCHEMA_VERSION,
+			entrants: EntrantsRef(&self.entrants),
+			matches: &self.matches,
+			byes: self.byes.clone(),
+			len_rounds: self.len_rounds,
+			rounds_played: self.rounds_played,
+			points: self.points,
+			tiebreakers: self.tiebreakers.clone(),
+		}
+		.serialize(serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<
+		'de,
+		E: Debug + Display + Clone + Deserialize<'de>,
+		M: Debug + Display + Clone + Default + Deserialize<'de>,
+		B: BattleSystem<E, M> + Default,
+	> Deserialize<'de> for Swiss<E, M, B>
+{
+	fn deserialize<D: Deserializer<'de>>(
+		deserializer: D,
+	) -> std::result::Result<Self, D::Error> {
+		let data =
+			SwissData::<Vec<E>, Vec<LeagueMatch<M>>>::deserialize(deserializer)?;
+		check_version(data.version)?;
+		check_matches(&data.matches, &data.byes, data.entrants.len())?;
+		Ok(Swiss {
+			entrants: entrant_arcs(data.entrants),
+			matches: data.matches,
+			byes: data.byes,
+			len_rounds: data.len_rounds,
+			rounds_played: data.rounds_played,
+			points: data.points,
+			tiebreakers: data.tiebreakers,
+			battle_system: B::default(),
+			rng: StdRng::from_entropy(),
+		})
+	}
+}
+
+/// Pairs off entrants in `order`, preferring neighbours, without repeating any pair in `played`. Returns `None` if that isn't possible within `budget` attempts.
+fn pair(
+	order: &[EntrantId],
+	played: &HashSet<(EntrantId, EntrantId)>,
+	budget: &mut usize,
+) -> Option<Vec<(EntrantId, EntrantId)>> {
+	let (first, rest) = match order.split_first() {
+		Some(split) => split,
+		None => return Some(vec![]),
+	};
+	for (i, other) in rest.iter().enumerate() {
+		if played.contains(&((*first).min(*other), (*first).max(*other))) {
+			continue;
+		}
+		if *budget == 0 {
+			return None;
+		}
+		*budget -= 1;
+		let remaining: Vec<EntrantId> = rest
+			.iter()
+			.enumerate()
+			.filter(|(j, _)| *j != i)
+			.map(|(_, eid)| *eid)
+			.collect();
+		if let Some(mut pairs) = pair(&remaining, played, budget) {
+			pairs.insert(0, (*first, *other));
+			return Some(pairs);
+		}
+	}
+	None
+}
@@ -1,4 +1,5 @@
 use crate::*;
+use rand::{Rng, RngCore};
 use std::sync::{Arc, RwLock};
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -14,12 +15,14 @@ impl std::fmt::Display for MyMetadata {
 	}
 }
 
-#[derive(Clone)]
+#[derive(Clone, Default)]
 struct U32BattleSystem;
 impl BattleSystem<u32, MyMetadata> for U32BattleSystem {
 	fn battle(
+		&mut self,
 		a_arc: Arc<RwLock<u32>>,
 		b_arc: Arc<RwLock<u32>>,
+		_: &mut dyn RngCore,
 	) -> BattleResult<MyMetadata> {
 		use TournamentRoundResult::*;
 		let a = a_arc.read().unwrap();
@@ -34,11 +37,25 @@ impl BattleSystem<u32, MyMetadata> for U32BattleSystem {
 		}
 	}
 	fn tiebreaker(
+		&mut self,
 		_: Arc<RwLock<u32>>,
 		_: Arc<RwLock<u32>>,
-	) -> (TournamentRoundResult, MyMetadata) {
-		use rand::prelude::*;
+		rng: &mut dyn RngCore,
+	) -> TiebreakerResult<MyMetadata> {
 		use TournamentRoundResult::*;
-		(if random::<f32>() > 0.5 { A } else { B }, MyMetadata::new())
+		Ok((
+			if rng.gen::<f32>() > 0.5 { A } else { B },
+			MyMetadata::new(),
+		))
 	}
 }
+
+#[test]
+fn u32_battle_system() -> Result<()> {
+	let mut t = Tournament::<u32, MyMetadata, U32BattleSystem>::new(vec![
+		1, 2, 3, 23, 35, 483, 9494, 9, 0, 102, 48,
+	])?;
+	t.solve()?;
+	assert_eq!(t.winner(*t.grand_finals())?.map(|e| e.0), Some(6));
+	Ok(())
+}
@@ -0,0 +1,320 @@
+use super::test_tournament::{
+	Event, IntBattleSystem, IntFighter, RecordingObserver,
+};
+use crate::*;
+use rand::prelude::*;
+use std::fmt;
+use std::sync::{Arc, RwLock};
+
+type IntDoubleElimination =
+	DoubleEliminationTournament<IntFighter, String, IntBattleSystem>;
+
+type TiringDoubleElimination =
+	DoubleEliminationTournament<TiringFighter, String, TiringBattleSystem>;
+
+/// A fighter that gets weaker every time it wins a round.
+#[derive(Debug, Clone, Copy)]
+struct TiringFighter(u32);
+impl fmt::Display for TiringFighter {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Tiring Fighter: {}", self.0)
+	}
+}
+
+#[derive(Clone, Default)]
+struct TiringBattleSystem;
+impl BattleSystem<TiringFighter, String> for TiringBattleSystem {
+	fn battle(
+		&mut self,
+		a_arc: Arc<RwLock<TiringFighter>>,
+		b_arc: Arc<RwLock<TiringFighter>>,
+		_: &mut dyn RngCore,
+	) -> BattleResult<String> {
+		use TournamentRoundResult::*;
+		let mut a = a_arc.write().unwrap();
+		let mut b = b_arc.write().unwrap();
+		if a.0 >= b.0 {
+			a.0 -= 10;
+			BattleResult::Solved(A, "A wins".to_string())
+		} else {
+			b.0 -= 10;
+			BattleResult::Solved(B, "B wins".to_string())
+		}
+	}
+	fn tiebreaker(
+		&mut self,
+		_: Arc<RwLock<TiringFighter>>,
+		_: Arc<RwLock<TiringFighter>>,
+		_: &mut dyn RngCore,
+	) -> TiebreakerResult<String> {
+		Ok((TournamentRoundResult::A, "A wins".to_string()))
+	}
+}
+
+fn random_double_elimination(
+	len: usize,
+	bracket_reset: bool,
+) -> Result<IntDoubleElimination> {
+	let mut rng = StdRng::seed_from_u64(len as u64);
+	let entrants = (0..len).map(|_| IntFighter(rng.gen())).collect();
+	IntDoubleElimination::new(entrants, bracket_reset)
+}
+
+#[test]
+fn needs_two_entrants() {
+	assert!(matches!(
+		IntDoubleElimination::new(vec![IntFighter(1)], false),
+		Err(TournamentError::NeedsAtLeastTwoEntrants)
+	));
+}
+
+#[test]
+fn round_counts() -> Result<()> {
+	for i in 2..100 {
+		let t = random_double_elimination(i, false)?;
+		assert_eq!(t.len_rounds(), 2 * i - 2);
+		let t = random_double_elimination(i, true)?;
+		assert_eq!(t.len_rounds(), 2 * i - 1);
+	}
+	Ok(())
+}
+
+#[test]
+fn every_loser_loses_twice() -> Result<()> {
+	for i in 2..60 {
+		let mut t = random_double_elimination(i, true)?;
+		t.solve()?;
+		let champion = t.champion()?.unwrap();
+		let mut losses = vec![0; i];
+		for node in t.graph().node_indices() {
+			if let TournamentNode::Round(TournamentRound::Complete { .. }) =
+				t.graph()[node]
+			{
+				losses[t.loser(node)?.unwrap().0] += 1;
+			}
+		}
+		for (id, count) in losses.into_iter().enumerate() {
+			if id == champion.0 {
+				assert!(count <= 1);
+			} else {
+				assert_eq!(count, 2);
+			}
+		}
+	}
+	Ok(())
+}
+
+#[test]
+fn strongest_wins_without_reset() -> Result<()> {
+	let mut t = IntDoubleElimination::new(
+		vec![
+			IntFighter(6),
+			IntFighter(1),
+			IntFighter(2),
+			IntFighter(9),
+			IntFighter(127),
+			IntFighter(8),
+			IntFighter(7),
+		],
+		true,
+	)?;
+	t.solve()?;
+	assert_eq!(t.champion()?, Some(EntrantId(4)));
+	assert_eq!(t.winner(*t.winners_finals())?, Some(EntrantId(4)));
+	assert_eq!(t.winner(*t.losers_finals())?, Some(EntrantId(3)));
+	let reset = *t.bracket_reset().unwrap();
+	assert!(t.graph()[reset].result().is_none());
+	Ok(())
+}
+
+#[test]
+fn withdraw() -> Result<()> {
+	let mut t = random_double_elimination(9, true)?;
+	t.withdraw(EntrantId(3))?;
+	assert!(t.is_withdrawn(EntrantId(3)));
+	t.solve()?;
+	assert_ne!(t.champion()?, Some(EntrantId(3)));
+	// The withdrawn entrant forfeits once in each bracket.
+	let mut forfeits = 0;
+	for node in t.graph().node_indices() {
+		if t.loser(node)? == Some(EntrantId(3)) {
+			assert_eq!(t.graph()[node].decision(), Some(RoundDecision::Walkover));
+			forfeits += 1;
+		}
+		if t.graph()[node].round().is_some() {
+			assert_ne!(t.winner(node)?, Some(EntrantId(3)));
+		}
+	}
+	assert_eq!(forfeits, 2);
+	Ok(())
+}
+
+#[test]
+fn bracket_reset() -> Result<()> {
+	let mut t = DoubleEliminationTournament::<
+		TiringFighter,
+		String,
+		TiringBattleSystem,
+	>::new(vec![TiringFighter(100), TiringFighter(95)], true)?;
+	t.solve()?;
+	// 100 wins the winners finals and tires to 90, then loses the grand finals
+	// to 95, who tires to 85 and loses the bracket reset.
+	assert_eq!(t.winner(*t.winners_finals())?, Some(EntrantId(0)));
+	assert_eq!(t.winner(*t.grand_finals())?, Some(EntrantId(1)));
+	assert_eq!(t.winner(*t.bracket_reset().unwrap())?, Some(EntrantId(0)));
+	assert_eq!(t.champion()?, Some(EntrantId(0)));
+	Ok(())
+}
+
+#[test]
+fn reproducible_from_seed() -> Result<()> {
+	let run = |seed: u64| -> Result<_> {
+		let entrants = (0..20).map(|i| IntFighter(i % 2)).collect();
+		let mut t = IntDoubleElimination::new(entrants, true)?;
+		t.set_seed(seed);
+		t.solve()?;
+		let metadata: Vec<Option<String>> = t
+			.graph()
+			.node_indices()
+			.map(|i| t.graph()[i].metadata().cloned())
+			.collect();
+		Ok((t.champion()?, metadata))
+	};
+	assert_eq!(run(3)?, run(3)?);
+	assert_ne!(run(3)?, run(4)?);
+	Ok(())
+}
+
+#[test]
+fn report_result() -> Result<()> {
+	use TournamentRoundResult::*;
+	let mut t =
+		IntDoubleElimination::new((1..=4).map(IntFighter).collect(), false)?;
+	let winners_finals = *t.winners_finals();
+	assert!(matches!(
+		t.report_result(winners_finals, A, String::new()),
+		Err(TournamentError::RoundNotReady(_))
+	));
+
+	// Both winners bracket semi-finals are upsets, reported by hand.
+	let (a, b) = Tournament::<IntFighter, String, IntBattleSystem>::_child_nodes(
+		t.graph(),
+		winners_finals,
+	)?;
+	for semi in [a, b].iter().copied() {
+		let (x, y) =
+			Tournament::<IntFighter, String, IntBattleSystem>::_child_nodes(
+				t.graph(),
+				semi,
+			)?;
+		let value = |node| {
+			t.entrant(t.winner(node).unwrap().unwrap())
+				.read()
+				.unwrap()
+				.0
+		};
+		let result = if value(x) < value(y) { A } else { B };
+		t.report_result(semi, result, "Upset".to_string())?;
+	}
+	t.solve()?;
+	// The strongest entrant drops to the losers bracket, then wins it and the grand finals.
+	assert_eq!(t.winner(*t.losers_finals())?, Some(EntrantId(3)));
+	assert_eq!(t.champion()?, Some(EntrantId(3)));
+	Ok(())
+}
+
+#[test]
+fn revert_round_restores_entrants() -> Result<()> {
+	let values = vec![100, 200, 300, 400];
+	let mut t = TiringDoubleElimination::new(
+		values.iter().copied().map(TiringFighter).collect(),
+		false,
+	)?;
+	let value = |t: &TiringDoubleElimination, i: usize| {
+		t.entrant(EntrantId(i)).read().unwrap().0
+	};
+	t.set_snapshots(true);
+	assert!(t.snapshots_enabled());
+	t.solve()?;
+	assert_ne!((0..4).map(|i| value(&t, i)).collect::<Vec<_>>(), values);
+
+	// Reverting one winners semi-final resets every round but the other.
+	let (a, b) =
+		Tournament::<TiringFighter, String, TiringBattleSystem>::_child_nodes(
+			t.graph(),
+			*t.winners_finals(),
+		)?;
+	let reverted = t.revert_round(a)?;
+	assert_eq!(reverted.len(), t.len_rounds() - 1);
+	assert!(!reverted.contains(&b));
+	assert!(reverted.contains(t.losers_finals()));
+	assert!(reverted.contains(t.grand_finals()));
+
+	// Entrants are restored to their state before the earliest reset round they played.
+	let b_winner = t.winner(b)?.unwrap();
+	for (i, v) in values.iter().enumerate() {
+		let tired = if i == b_winner.0 { 10 } else { 0 };
+		assert_eq!(value(&t, i), v - tired);
+	}
+	t.revert_round(b)?;
+	assert_eq!((0..4).map(|i| value(&t, i)).collect::<Vec<_>>(), values);
+
+	// Without snapshots, only the rounds are reset.
+	t.solve()?;
+	t.set_snapshots(false);
+	let tired: Vec<u32> = (0..4).map(|i| value(&t, i)).collect();
+	t.revert_round(a)?;
+	assert_eq!((0..4).map(|i| value(&t, i)).collect::<Vec<_>>(), tired);
+	Ok(())
+}
+
+#[test]
+fn observer() -> Result<()> {
+	for &bracket_reset in [false, true].iter() {
+		let log = Arc::new(RwLock::new(vec![]));
+		let mut t = random_double_elimination(12, bracket_reset)?;
+		t.add_observer(RecordingObserver(log.clone()));
+		t.solve()?;
+
+		let events = log.read().unwrap().clone();
+		let played = t
+			.graph()
+			.node_indices()
+			.filter(|id| t.graph()[*id].result().is_some())
+			.count();
+		let starts = events
+			.iter()
+			.filter(|e| matches!(e, Event::Start(..)))
+			.count();
+		let completes = events
+			.iter()
+			.filter(|e| matches!(e, Event::Complete(..)))
+			.count();
+		assert_eq!((starts, completes), (played, played));
+		let champions: Vec<&Event> = events
+			.iter()
+			.filter(|e| matches!(e, Event::Champion(_)))
+			.collect();
+		assert_eq!(champions, vec![&Event::Champion(t.champion()?.unwrap())]);
+		assert_eq!(events.last(), champions.first().copied());
+	}
+	Ok(())
+}
+
+#[test]
+fn to_dot() -> Result<()> {
+	let mut t = random_double_elimination(6, true)?;
+	let dot = t.to_dot()?;
+	assert_eq!(dot.matches(" -> ").count(), t.graph().edge_count());
+	assert_eq!(
+		dot.matches("[label=\"Loser\", style=dashed]").count(),
+		t.graph()
+			.node_indices()
+			.filter(|id| matches!(t.graph()[*id], TournamentNode::Loser))
+			.count()
+	);
+	assert!(!dot.contains("goldenrod"));
+	t.solve()?;
+	assert!(t.to_dot()?.contains("goldenrod"));
+	Ok(())
+}
@@ -1,11 +1,17 @@
-use crate::tournament::*;
+use crate::*;
 use rand::prelude::*;
+use std::cell::Cell;
 use std::fmt;
+use std::rc::Rc;
+use std::sync::{Arc, RwLock};
 
-#[derive(Debug)]
+type JankenTournament = Tournament<JankenFighter, String, JankenBattleSystem>;
+
+/// A rock-paper-scissors player, with a weight for each throw.
+#[derive(Debug, Clone, Copy)]
 struct JankenFighter(f32, f32, f32);
 
-#[derive(Eq, PartialEq)]
+#[derive(Debug, Eq, PartialEq)]
 pub enum JankenRoll {
 	Rock,
 	Paper,
@@ -25,10 +31,10 @@ impl fmt::Display for JankenFighter {
 }
 
 impl JankenFighter {
-	fn roll(&self) -> JankenRoll {
-		let rock_roll = random::<f32>() * self.0;
-		let paper_roll = random::<f32>() * self.1;
-		let scissors_roll = random::<f32>() * self.2;
+	fn roll(&self, rng: &mut dyn RngCore) -> JankenRoll {
+		let rock_roll = rng.gen::<f32>() * self.0;
+		let paper_roll = rng.gen::<f32>() * self.1;
+		let scissors_roll = rng.gen::<f32>() * self.2;
 		let mut res = JankenRoll::Rock;
 		if paper_roll > rock_roll {
 			res = JankenRoll::Paper;
@@ -42,29 +48,181 @@ impl JankenFighter {
 	}
 }
 
-impl Entrant for JankenFighter {
-	fn battle(&mut self, enemy: &mut JankenFighter) -> TournamentRoundResult {
+#[derive(Clone, Default)]
+struct JankenBattleSystem;
+impl BattleSystem<JankenFighter, String> for JankenBattleSystem {
+	fn battle(
+		&mut self,
+		a_arc: Arc<RwLock<JankenFighter>>,
+		b_arc: Arc<RwLock<JankenFighter>>,
+		rng: &mut dyn RngCore,
+	) -> BattleResult<String> {
 		use JankenRoll::*;
 		use TournamentRoundResult::*;
-		let my_roll = self.roll();
-		let enemy_roll = enemy.roll();
-
-		match my_roll {
-			Rock => match enemy_roll {
-				Rock => Tie,
-				Paper => WinB,
-				Scissors => WinA,
-			},
-			Paper => match enemy_roll {
-				Rock => WinA,
-				Paper => Tie,
-				Scissors => WinB,
-			},
-			Scissors => match enemy_roll {
-				Rock => WinB,
-				Paper => WinA,
-				Scissors => Tie,
-			},
+		let a_roll = a_arc.read().unwrap().roll(rng);
+		let b_roll = b_arc.read().unwrap().roll(rng);
+
+		let winner = match (&a_roll, &b_roll) {
+			(Rock, Scissors) | (Paper, Rock) | (Scissors, Paper) => A,
+			(Rock, Paper) | (Paper, Scissors) | (Scissors, Rock) => B,
+			_ => return BattleResult::Tie,
+		};
+		BattleResult::Solved(winner, format!("{:?} vs {:?}", a_roll, b_roll))
+	}
+	fn tiebreaker(
+		&mut self,
+		_: Arc<RwLock<JankenFighter>>,
+		_: Arc<RwLock<JankenFighter>>,
+		rng: &mut dyn RngCore,
+	) -> TiebreakerResult<String> {
+		use TournamentRoundResult::*;
+		if rng.gen::<bool>() {
+			Ok((A, "A won by coin toss".to_string()))
+		} else {
+			Ok((B, "B won by coin toss".to_string()))
 		}
 	}
 }
+
+fn janken_tournament() -> Result<JankenTournament> {
+	JankenTournament::new(vec![
+		JankenFighter(1.0, 0.0, 0.0),
+		JankenFighter(0.0, 1.0, 0.0),
+		JankenFighter(0.0, 0.0, 1.0),
+		JankenFighter(1.0, 1.0, 1.0),
+		JankenFighter(0.2, 0.5, 0.3),
+	])
+}
+
+#[test]
+fn simulate() -> Result<()> {
+	let t = janken_tournament()?;
+	let simulation = t.simulate(500, 7)?;
+	assert_eq!(simulation.runs(), 500);
+	assert_eq!(simulation.odds().len(), t.len_entrants());
+	assert_eq!(simulation, t.simulate(500, 7)?);
+
+	let layers = 3;
+	let champion: f64 = simulation.odds().iter().map(|o| o.champion).sum();
+	assert!((champion - 1.0).abs() < 1e-9);
+	for odds in simulation.odds() {
+		assert_eq!(odds.reached.len(), layers);
+		assert!(odds.reached.windows(2).all(|w| w[0] <= w[1]));
+		assert!(odds.champion <= odds.reached[0]);
+	}
+	let finalists: f64 = simulation.odds().iter().map(|o| o.reached[0]).sum();
+	assert!((finalists - 2.0).abs() < 1e-9);
+	// Every entrant plays in the opening layer, or skips it with a bye.
+	for odds in simulation.odds() {
+		assert_eq!(odds.reached[layers - 1], 1.0);
+	}
+
+	// The tournament itself is left unsolved.
+	assert_eq!(t.len_rounds_complete(), 0);
+	Ok(())
+}
+
+#[test]
+fn simulate_one_sided() -> Result<()> {
+	// Paper always beats rock.
+	let t = JankenTournament::new(vec![
+		JankenFighter(1.0, 0.0, 0.0),
+		JankenFighter(0.0, 1.0, 0.0),
+	])?;
+	let simulation = t.simulate(50, 1)?;
+	assert_eq!(simulation.entrant_odds(EntrantId(0)).unwrap().champion, 0.0);
+	assert_eq!(simulation.entrant_odds(EntrantId(1)).unwrap().champion, 1.0);
+	assert_eq!(simulation.entrant_odds(EntrantId(2)), None);
+
+	assert!(matches!(
+		t.simulate(0, 1),
+		Err(TournamentError::NeedsAtLeastOneRun)
+	));
+	Ok(())
+}
+
+#[test]
+fn simulate_parallel() -> Result<()> {
+	let t = janken_tournament()?;
+	let sequential = t.simulate(300, 99)?;
+	for threads in [0, 1, 2, 7].iter() {
+		assert_eq!(t.simulate_parallel(300, 99, *threads)?, sequential);
+	}
+	Ok(())
+}
+
+#[test]
+fn simulate_partially_solved() -> Result<()> {
+	let mut t = janken_tournament()?;
+	t.set_seed(3);
+	let round = t.solve_next()?.unwrap();
+	let winner = t.winner(round)?.unwrap();
+	let loser = t.loser(round)?.unwrap();
+
+	let simulation = t.simulate(200, 3)?;
+	let depth = t.layers()?.len() - 1;
+	assert_eq!(simulation.entrant_odds(loser).unwrap().champion, 0.0);
+	assert_eq!(
+		simulation.entrant_odds(loser).unwrap().reached[depth - 1],
+		0.0
+	);
+	assert_eq!(
+		simulation.entrant_odds(winner).unwrap().reached[depth - 1],
+		1.0
+	);
+	Ok(())
+}
+
+/// A fighter shared through an `Rc`, so it's neither `Send` nor `Sync`.
+#[derive(Debug, Clone)]
+struct SharedFighter(Rc<JankenFighter>);
+impl fmt::Display for SharedFighter {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		self.0.fmt(f)
+	}
+}
+
+/// Plays janken, counting battles across every clone. Isn't `Send` either.
+#[derive(Clone, Default)]
+struct SharedBattleSystem(Rc<Cell<usize>>);
+impl SharedBattleSystem {
+	fn unwrap(fighter: Arc<RwLock<SharedFighter>>) -> Arc<RwLock<JankenFighter>> {
+		Arc::new(RwLock::new(*fighter.read().unwrap().0))
+	}
+}
+impl BattleSystem<SharedFighter, String> for SharedBattleSystem {
+	fn battle(
+		&mut self,
+		a: Arc<RwLock<SharedFighter>>,
+		b: Arc<RwLock<SharedFighter>>,
+		rng: &mut dyn RngCore,
+	) -> BattleResult<String> {
+		self.0.set(self.0.get() + 1);
+		JankenBattleSystem.battle(Self::unwrap(a), Self::unwrap(b), rng)
+	}
+	fn tiebreaker(
+		&mut self,
+		a: Arc<RwLock<SharedFighter>>,
+		b: Arc<RwLock<SharedFighter>>,
+		rng: &mut dyn RngCore,
+	) -> TiebreakerResult<String> {
+		JankenBattleSystem.tiebreaker(Self::unwrap(a), Self::unwrap(b), rng)
+	}
+}
+
+#[test]
+fn simulate_without_send() -> Result<()> {
+	let t = janken_tournament()?;
+	let entrants = (0..t.len_entrants())
+		.map(|i| SharedFighter(Rc::new(*t.entrant(EntrantId(i)).read().unwrap())))
+		.collect();
+	let battles = Rc::new(Cell::new(0));
+	let shared = Tournament::new_with_battle_system(
+		entrants,
+		Seeding::Ordered,
+		SharedBattleSystem(battles.clone()),
+	)?;
+	assert_eq!(shared.simulate(100, 5)?, t.simulate(100, 5)?);
+	assert!(battles.get() >= 100 * t.len_rounds());
+	Ok(())
+}
@@ -0,0 +1,145 @@
+use super::test_tournament::{IntBattleSystem, IntFighter};
+use crate::*;
+use rand::RngCore;
+use std::collections::HashSet;
+use std::sync::{Arc, RwLock};
+
+type IntRoundRobin = RoundRobin<IntFighter, String, IntBattleSystem>;
+
+/// The larger number wins, except that 10 always beats 40.
+#[derive(Clone, Default)]
+struct UpsetBattleSystem;
+impl BattleSystem<IntFighter, String> for UpsetBattleSystem {
+	fn battle(
+		&mut self,
+		a_arc: Arc<RwLock<IntFighter>>,
+		b_arc: Arc<RwLock<IntFighter>>,
+		_: &mut dyn RngCore,
+	) -> BattleResult<String> {
+		use TournamentRoundResult::*;
+		let a = a_arc.read().unwrap().0;
+		let b = b_arc.read().unwrap().0;
+		let winner = match (a, b) {
+			(10, 40) => A,
+			(40, 10) => B,
+			_ if a > b => A,
+			_ => B,
+		};
+		BattleResult::Solved(winner, format!("{} vs {}", a, b))
+	}
+	fn tiebreaker(
+		&mut self,
+		_: Arc<RwLock<IntFighter>>,
+		_: Arc<RwLock<IntFighter>>,
+		_: &mut dyn RngCore,
+	) -> TiebreakerResult<String> {
+		Ok((TournamentRoundResult::A, String::new()))
+	}
+}
+
+fn fighters(values: &[u32]) -> Vec<IntFighter> {
+	values.iter().map(|v| IntFighter(*v)).collect()
+}
+
+#[test]
+fn schedule() -> Result<()> {
+	for len in 1..30 {
+		for legs in 1..4 {
+			let t = IntRoundRobin::new(fighters(&vec![1; len]), legs)?;
+			assert_eq!(t.len_matches(), len * (len - 1) / 2 * legs);
+
+			let mut pairs = HashSet::new();
+			for m in t.matches() {
+				assert_ne!(m.a, m.b);
+				pairs.insert((m.a.0.min(m.b.0), m.a.0.max(m.b.0)));
+			}
+			assert_eq!(pairs.len(), len * (len - 1) / 2);
+
+			for round in 0..t.len_rounds() {
+				let mut seen = HashSet::new();
+				for m in t.matches().iter().filter(|m| m.round == round) {
+					assert!(seen.insert(m.a));
+					assert!(seen.insert(m.b));
+				}
+			}
+		}
+	}
+	Ok(())
+}
+
+#[test]
+fn needs_a_leg() {
+	assert!(matches!(
+		IntRoundRobin::new(fighters(&[1, 2]), 0),
+		Err(TournamentError::NeedsAtLeastOneLeg)
+	));
+	assert!(matches!(
+		IntRoundRobin::new(vec![], 1),
+		Err(TournamentError::NeedsAtLeastOneEntrant)
+	));
+}
+
+#[test]
+fn ties_are_draws() -> Result<()> {
+	let mut t = IntRoundRobin::new(fighters(&[5, 5, 5, 5]), 2)?;
+	t.solve()?;
+	assert_eq!(t.len_matches_complete(), 12);
+	for standing in t.standings() {
+		assert_eq!(standing.played, 6);
+		assert_eq!(standing.ties, 6);
+		assert_eq!(standing.points, 6);
+	}
+	assert!(t
+		.matches()
+		.iter()
+		.all(|m| m.result() == Some(&MatchResult::Tie)));
+	Ok(())
+}
+
+#[test]
+fn standings() -> Result<()> {
+	let mut t = IntRoundRobin::new(fighters(&[3, 1, 4, 2]), 1)?;
+	t.solve_round(0)?;
+	assert_eq!(t.len_matches_complete(), 2);
+	t.solve()?;
+	let standings = t.standings();
+	let order: Vec<usize> = standings.iter().map(|s| s.entrant.0).collect();
+	assert_eq!(order, vec![2, 0, 3, 1]);
+	assert_eq!(standings[0].points, 9);
+	assert_eq!(standings[0].wins, 3);
+	assert_eq!(standings[3].losses, 3);
+	Ok(())
+}
+
+#[test]
+fn sonneborn_berger() -> Result<()> {
+	let mut t = IntRoundRobin::new(fighters(&[3, 3, 2, 1]), 1)?;
+	t.solve()?;
+	let sonneborn_berger = |t: &IntRoundRobin| -> Vec<u32> {
+		let mut standings = t.standings();
+		standings.sort_by_key(|s| s.entrant);
+		standings.iter().map(|s| s.sonneborn_berger).collect()
+	};
+	assert_eq!(sonneborn_berger(&t), vec![13, 13, 0, 0]);
+	t.set_points(PointSystem::new(1, 0, 0));
+	assert_eq!(sonneborn_berger(&t), vec![4, 4, 0, 0]);
+	Ok(())
+}
+
+#[test]
+fn head_to_head() -> Result<()> {
+	let mut t = RoundRobin::<IntFighter, String, UpsetBattleSystem>::new(
+		fighters(&[10, 20, 30, 40]),
+		1,
+	)?;
+	t.solve()?;
+	let order = |t: &RoundRobin<_, _, _>| -> Vec<usize> {
+		t.standings().iter().map(|s| s.entrant.0).collect()
+	};
+	assert_eq!(order(&t), vec![3, 2, 1, 0]);
+	t.set_tiebreakers(vec![]);
+	assert_eq!(order(&t), vec![2, 3, 0, 1]);
+	t.set_points(PointSystem::new(1, 0, 0));
+	assert_eq!(t.standings()[0].points, 2);
+	Ok(())
+}
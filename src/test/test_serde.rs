@@ -0,0 +1,219 @@
+use super::test_tournament::{IntBattleSystem, IntFighter};
+use crate::*;
+use petgraph::graph::NodeIndex;
+use serde_json::{json, Value};
+
+type IntTournament = Tournament<IntFighter, String, IntBattleSystem>;
+
+fn fighters(values: &[u32]) -> Vec<IntFighter> {
+	values.iter().map(|v| IntFighter(*v)).collect()
+}
+
+/// Every round's winner, in node order.
+fn winners(t: &IntTournament) -> Result<Vec<Option<EntrantId>>> {
+	t.graph()
+		.node_indices()
+		.filter(|i| t.graph()[*i].round().is_some())
+		.map(|i| t.winner(i))
+		.collect()
+}
+
+fn schema_example() -> Value {
+	json!({
+		"version": 4,
+		"entrants": [3, 1, 2],
+		"graph": {
+			"nodes": [
+				{ "Round": "Incomplete" },
+				{ "Round": { "Complete": { "result": "A", "metadata": "2 beat 1", "decision": "Played", "series": [] } } },
+				{ "Entrant": 0 },
+				{ "Entrant": 1 },
+				{ "Entrant": 2 }
+			],
+			"node_holes": [],
+			"edge_property": "directed",
+			"edges": [[0, 1, "A"], [0, 2, "B"], [1, 3, "A"], [1, 4, "B"]]
+		},
+		"grand_finals": 0,
+		"third_place": null,
+		"withdrawn": [],
+		"best_of": { "default": 1, "depths": [], "rounds": [] }
+	})
+}
+
+#[test]
+fn tournament_round_trip() -> Result<()> {
+	let mut t = IntTournament::new(fighters(&[8, 3, 6, 1, 7, 2, 5, 4, 9]))?;
+	let (a, _) = t.child_nodes(*t.grand_finals())?;
+	t.solve_round(a)?;
+
+	let json = serde_json::to_string(&t).unwrap();
+	let mut loaded: IntTournament = serde_json::from_str(&json).unwrap();
+	assert_eq!(loaded.len_entrants(), t.len_entrants());
+	assert_eq!(loaded.len_rounds_complete(), t.len_rounds_complete());
+	assert_eq!(winners(&loaded)?, winners(&t)?);
+	assert_eq!(serde_json::to_string(&loaded).unwrap(), json);
+
+	loaded.solve()?;
+	t.solve()?;
+	assert_eq!(loaded.winner(*loaded.grand_finals())?, Some(EntrantId(8)));
+	assert_eq!(winners(&loaded)?, winners(&t)?);
+	Ok(())
+}
+
+#[test]
+fn third_place_round_trip() -> Result<()> {
+	let mut t = IntTournament::new(fighters(&[8, 3, 6, 1, 7]))?
+		.with_third_place_playoff()?;
+	let (a, _) = t.child_nodes(*t.grand_finals())?;
+	t.solve_round(a)?;
+
+	let json = serde_json::to_string(&t).unwrap();
+	let mut loaded: IntTournament = serde_json::from_str(&json).unwrap();
+	assert_eq!(loaded.third_place(), t.third_place());
+	assert_eq!(serde_json::to_string(&loaded).unwrap(), json);
+
+	loaded.solve()?;
+	assert_eq!(
+		loaded.winner(*loaded.third_place().unwrap())?,
+		Some(EntrantId(2))
+	);
+
+	let mut missing_third_place = serde_json::to_value(&t).unwrap();
+	missing_third_place["third_place"] = json!(100);
+	assert!(serde_json::from_value::<IntTournament>(missing_third_place).is_err());
+	Ok(())
+}
+
+#[test]
+fn series_round_trip() -> Result<()> {
+	let mut t = IntTournament::new(fighters(&[8, 3, 6, 1, 7]))?;
+	t.set_best_of(3)?;
+	t.set_best_of_round(*t.grand_finals(), 5)?;
+	let (a, _) = t.child_nodes(*t.grand_finals())?;
+	t.solve_round(a)?;
+
+	let json = serde_json::to_string(&t).unwrap();
+	let mut loaded: IntTournament = serde_json::from_str(&json).unwrap();
+	assert_eq!(loaded.graph()[a].series().map(Vec::len), Some(2));
+	assert_eq!(loaded.best_of(*loaded.grand_finals())?, 5);
+	assert_eq!(serde_json::to_string(&loaded).unwrap(), json);
+
+	loaded.solve()?;
+	assert_eq!(
+		loaded.graph()[*loaded.grand_finals()]
+			.round()
+			.unwrap()
+			.score(),
+		Some((0, 3))
+	);
+	Ok(())
+}
+
+#[test]
+fn documented_schema() -> Result<()> {
+	let t: IntTournament = serde_json::from_value(schema_example()).unwrap();
+	assert_eq!(t.len_rounds_complete(), 1);
+	assert_eq!(serde_json::to_value(&t).unwrap(), schema_example());
+
+	// Version 1 had no walkovers, withdrawn entrants, series or third place playoffs.
+	let mut v1 = schema_example();
+	v1["version"] = json!(1);
+	v1.as_object_mut().unwrap().remove("third_place");
+	v1.as_object_mut().unwrap().remove("withdrawn");
+	v1.as_object_mut().unwrap().remove("best_of");
+	let round = v1["graph"]["nodes"][1]["Round"]["Complete"]
+		.as_object_mut()
+		.unwrap();
+	round.remove("decision");
+	round.remove("series");
+	let t: IntTournament = serde_json::from_value(v1).unwrap();
+	assert_eq!(
+		t.graph()[NodeIndex::new(1)].decision(),
+		Some(RoundDecision::Played)
+	);
+	assert_eq!(t.graph()[NodeIndex::new(1)].series().map(Vec::len), Some(0));
+	assert!(t.withdrawn().is_empty());
+	assert_eq!(t.best_of(NodeIndex::new(0))?, 1);
+	assert_eq!(t.third_place(), None);
+	Ok(())
+}
+
+#[test]
+fn rejects_invalid_data() {
+	let mut newer = schema_example();
+	newer["version"] = json!(SCHEMA_VERSION + 1);
+	assert!(serde_json::from_value::<IntTournament>(newer).is_err());
+
+	let mut missing_entrant = schema_example();
+	missing_entrant["entrants"] = json!([3, 1]);
+	assert!(serde_json::from_value::<IntTournament>(missing_entrant).is_err());
+
+	let mut missing_root = schema_example();
+	missing_root["grand_finals"] = json!(5);
+	assert!(serde_json::from_value::<IntTournament>(missing_root).is_err());
+
+	let mut missing_withdrawn = schema_example();
+	missing_withdrawn["withdrawn"] = json!([3]);
+	assert!(serde_json::from_value::<IntTournament>(missing_withdrawn).is_err());
+}
+
+#[test]
+fn double_elimination_round_trip() -> Result<()> {
+	type IntDoubleElimination =
+		DoubleEliminationTournament<IntFighter, String, IntBattleSystem>;
+	let mut t = IntDoubleElimination::new(fighters(&[4, 7, 1, 5, 3, 6]), true)?;
+	t.solve_round(*t.winners_finals())?;
+
+	let json = serde_json::to_string(&t).unwrap();
+	let mut loaded: IntDoubleElimination = serde_json::from_str(&json).unwrap();
+	assert_eq!(loaded.bracket_reset(), t.bracket_reset());
+	assert_eq!(loaded.losers_finals(), t.losers_finals());
+	assert_eq!(
+		loaded.winner(*loaded.winners_finals())?,
+		t.winner(*t.winners_finals())?
+	);
+	assert_eq!(serde_json::to_string(&loaded).unwrap(), json);
+
+	loaded.solve()?;
+	assert_eq!(loaded.champion()?.map(|eid| eid.0), Some(1));
+	Ok(())
+}
+
+#[test]
+fn round_robin_round_trip() -> Result<()> {
+	type IntRoundRobin = RoundRobin<IntFighter, String, IntBattleSystem>;
+	let mut t = IntRoundRobin::new(fighters(&[3, 1, 4, 2, 5]), 2)?;
+	t.solve_round(0)?;
+	t.set_points(PointSystem::new(2, 1, 0));
+
+	let json = serde_json::to_string(&t).unwrap();
+	let mut loaded: IntRoundRobin = serde_json::from_str(&json).unwrap();
+	assert_eq!(loaded.len_matches_complete(), t.len_matches_complete());
+	assert_eq!(loaded.points(), t.points());
+	assert_eq!(loaded.standings(), t.standings());
+
+	loaded.solve()?;
+	t.solve()?;
+	assert_eq!(loaded.standings(), t.standings());
+	Ok(())
+}
+
+#[test]
+fn swiss_round_trip() -> Result<()> {
+	type IntSwiss = Swiss<IntFighter, String, IntBattleSystem>;
+	let mut t = IntSwiss::new(fighters(&[3, 1, 4, 2, 5]), 3)?;
+	t.solve_next_round()?;
+
+	let json = serde_json::to_string(&t).unwrap();
+	let mut loaded: IntSwiss = serde_json::from_str(&json).unwrap();
+	assert_eq!(loaded.rounds_played(), 1);
+	assert_eq!(loaded.byes(), t.byes());
+	assert_eq!(loaded.standings(), t.standings());
+
+	loaded.solve()?;
+	t.solve()?;
+	assert_eq!(loaded.byes(), t.byes());
+	assert_eq!(loaded.standings(), t.standings());
+	Ok(())
+}
@@ -0,0 +1,115 @@
+use super::test_tournament::{FlakyBattleSystem, IntBattleSystem, IntFighter};
+use crate::*;
+use std::collections::HashSet;
+
+type IntSwiss = Swiss<IntFighter, String, IntBattleSystem>;
+
+fn fighters(values: &[u32]) -> Vec<IntFighter> {
+	values.iter().map(|v| IntFighter(*v)).collect()
+}
+
+#[test]
+fn no_rematches() -> Result<()> {
+	for len in 2..17 {
+		// Few enough rounds that a pairing without rematches always exists.
+		let rounds = std::cmp::max(1, (len - len % 2) / 2);
+		let values: Vec<u32> = (0..len as u32).map(|i| i * 7 % 11).collect();
+		let mut t = IntSwiss::new(fighters(&values), rounds)?;
+		t.solve()?;
+		assert_eq!(t.rounds_played(), rounds);
+		assert_eq!(t.solve_next_round()?, None);
+
+		let mut pairs = HashSet::new();
+		for m in t.matches() {
+			assert!(pairs.insert((m.a.min(m.b), m.a.max(m.b))));
+		}
+		for round in 0..rounds {
+			let mut seen: HashSet<EntrantId> = HashSet::new();
+			for m in t.matches().iter().filter(|m| m.round == round) {
+				assert!(seen.insert(m.a));
+				assert!(seen.insert(m.b));
+			}
+			if len % 2 == 1 {
+				assert!(seen.insert(t.byes()[round]));
+			}
+			assert_eq!(seen.len(), len);
+		}
+	}
+	Ok(())
+}
+
+#[test]
+fn byes() -> Result<()> {
+	let mut t = IntSwiss::new(fighters(&[1, 2, 3, 4, 5]), 5)?;
+	t.solve()?;
+	let byes: HashSet<EntrantId> = t.byes().iter().cloned().collect();
+	assert_eq!(byes.len(), 5);
+	for standing in t.standings() {
+		assert_eq!(standing.byes, 1);
+		assert_eq!(standing.played, 4);
+	}
+	Ok(())
+}
+
+#[test]
+fn strongest_wins() -> Result<()> {
+	let mut t = IntSwiss::new(fighters(&[5, 8, 1, 3, 7, 2, 6, 4]), 3)?;
+	t.solve()?;
+	let standings = t.standings();
+	assert_eq!(standings[0].entrant, EntrantId(1));
+	assert_eq!(standings[0].points, 9);
+	assert!(standings[1].points < 9);
+	Ok(())
+}
+
+#[test]
+fn tiebreaks() -> Result<()> {
+	let mut t = IntSwiss::new(fighters(&[4, 3, 2, 1]), 2)?;
+	t.solve_next_round()?;
+	let points: Vec<u32> = t.standings().iter().map(|s| s.points).collect();
+	assert_eq!(points, vec![3, 3, 0, 0]);
+	t.solve_next_round()?;
+	let standings = t.standings();
+	let order: Vec<usize> = standings.iter().map(|s| s.entrant.0).collect();
+	assert_eq!(order, vec![0, 1, 2, 3]);
+	assert!(standings.iter().all(|s| s.buchholz == 6));
+	assert_eq!(standings[0].sonneborn_berger, 12);
+	assert_eq!(standings[1].sonneborn_berger, 0);
+	Ok(())
+}
+
+#[test]
+fn failing_battle_system() -> Result<()> {
+	use std::error::Error;
+
+	let mut t = Swiss::new_with_battle_system(
+		fighters(&[1, 2, 3, 4, 5]),
+		2,
+		FlakyBattleSystem {
+			battles: 0,
+			fail_at: 3,
+		},
+	)?;
+	t.solve_next_round()?;
+	// The first match of the second round fails, so the whole round is thrown away.
+	let err = t.solve_next_round().unwrap_err();
+	assert!(matches!(err, TournamentError::MatchFailed(2, _)));
+	assert_eq!(err.source().unwrap().to_string(), "simulator timed out");
+	assert_eq!(t.rounds_played(), 1);
+	assert_eq!(t.matches().len(), 2);
+	assert_eq!(t.byes().len(), 1);
+
+	t.solve()?;
+	assert_eq!(t.rounds_played(), 2);
+	assert_eq!(t.matches().len(), 4);
+	assert_eq!(t.byes().len(), 2);
+	Ok(())
+}
+
+#[test]
+fn rematches_when_unavoidable() -> Result<()> {
+	let mut t = IntSwiss::new(fighters(&[1, 2, 3, 4]), 5)?;
+	t.solve()?;
+	assert_eq!(t.matches().len(), 10);
+	Ok(())
+}
@@ -1,11 +1,14 @@
 use crate::*;
 use num_format::{Locale, ToFormattedString};
+use petgraph::graph::NodeIndex;
+use petgraph::{Direction, Graph};
 use rand::prelude::*;
 use std::fmt;
 use std::sync::{Arc, RwLock};
 
 #[derive(Debug, Clone, Copy)]
-struct IntFighter(u32);
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub(crate) struct IntFighter(pub u32);
 impl fmt::Display for IntFighter {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		write!(
@@ -16,13 +19,15 @@ impl fmt::Display for IntFighter {
 	}
 }
 
-#[derive(Clone)]
-struct IntBattleSystem;
+#[derive(Clone, Default)]
+pub(crate) struct IntBattleSystem;
 
 impl BattleSystem<IntFighter, String> for IntBattleSystem {
 	fn battle(
+		&mut self,
 		a_arc: Arc<RwLock<IntFighter>>,
 		b_arc: Arc<RwLock<IntFighter>>,
+		_: &mut dyn RngCore,
 	) -> BattleResult<String> {
 		use TournamentRoundResult::*;
 		let a = *a_arc.read().unwrap();
@@ -38,15 +43,17 @@ impl BattleSystem<IntFighter, String> for IntBattleSystem {
 		BattleResult::Solved(winner, format!("{} wins by {}!", winner_val, delta))
 	}
 	fn tiebreaker(
+		&mut self,
 		_: Arc<RwLock<IntFighter>>,
 		_: Arc<RwLock<IntFighter>>,
-	) -> (TournamentRoundResult, String) {
+		rng: &mut dyn RngCore,
+	) -> TiebreakerResult<String> {
 		use TournamentRoundResult::*;
-		let res: f32 = random();
+		let res: f32 = rng.gen();
 		if res > 0.5 {
-			(A, "A won by random tiebreaker.".to_string())
+			Ok((A, "A won by random tiebreaker.".to_string()))
 		} else {
-			(B, "B won by random tiebreaker.".to_string())
+			Ok((B, "B won by random tiebreaker.".to_string()))
 		}
 	}
 }
@@ -54,9 +61,9 @@ impl BattleSystem<IntFighter, String> for IntBattleSystem {
 fn random_int_tournament(
 	len: usize,
 ) -> Result<Tournament<IntFighter, String, IntBattleSystem>> {
+	let mut rng = StdRng::seed_from_u64(len as u64);
 	Tournament::<IntFighter, String, IntBattleSystem>::new_from_gen(len, || {
-		let r = IntFighter(random::<u32>());
-		r
+		IntFighter(rng.gen())
 	})
 }
 
@@ -93,17 +100,21 @@ fn tournament_node_counts() -> Result<()> {
 		let entrants = t
 			.graph()
 			.node_indices()
-			.filter(|index| match t.graph().node_weight(*index).unwrap() {
-				TournamentNode::Entrant(_) => true,
-				_ => false,
+			.filter(|index| {
+				matches!(
+					t.graph().node_weight(*index).unwrap(),
+					TournamentNode::Entrant(_)
+				)
 			})
 			.count();
 		let rounds = t
 			.graph()
 			.node_indices()
-			.filter(|index| match t.graph().node_weight(*index).unwrap() {
-				TournamentNode::Round(_) => true,
-				_ => false,
+			.filter(|index| {
+				matches!(
+					t.graph().node_weight(*index).unwrap(),
+					TournamentNode::Round(_)
+				)
 			})
 			.count();
 		// println!("COUNT: {}", i);
@@ -193,3 +204,1562 @@ fn print() -> Result<()> {
 	print_tournament(&t)?;
 	Ok(())
 }
+
+fn seeded_int_tournament(
+	len: usize,
+	seeding: Seeding,
+) -> Result<Tournament<IntFighter, String, IntBattleSystem>> {
+	// Seed 1 is the strongest entrant.
+	let entrants = (0..len).map(|i| IntFighter((len - i) as u32)).collect();
+	Tournament::<IntFighter, String, IntBattleSystem>::new_seeded(
+		entrants, seeding,
+	)
+}
+
+fn opening_rounds(
+	t: &Tournament<IntFighter, String, IntBattleSystem>,
+) -> Result<Vec<(usize, usize)>> {
+	let mut pairs = vec![];
+	for node in t.graph().node_indices() {
+		if let TournamentNode::Round(_) = t.graph()[node] {
+			let (a, b) = t.child_nodes(node)?;
+			if let (TournamentNode::Entrant(a), TournamentNode::Entrant(b)) =
+				(&t.graph()[a], &t.graph()[b])
+			{
+				pairs.push((a.0.min(b.0), a.0.max(b.0)));
+			}
+		}
+	}
+	pairs.sort();
+	Ok(pairs)
+}
+
+#[test]
+fn seeded_node_counts() -> Result<()> {
+	for i in 1..100 {
+		for seeding in [Seeding::Standard, Seeding::Snake] {
+			let mut t = seeded_int_tournament(i, seeding)?;
+			assert_eq!(t.len_entrants(), i);
+			assert_eq!(t.len_rounds(), i - 1);
+			t.solve()?;
+			assert_eq!(t.winner(*t.grand_finals())?, Some(EntrantId(0)));
+		}
+	}
+	Ok(())
+}
+
+#[test]
+fn standard_seeding() -> Result<()> {
+	let mut t = seeded_int_tournament(16, Seeding::Standard)?;
+	assert_eq!(
+		opening_rounds(&t)?,
+		vec![
+			(0, 15),
+			(1, 14),
+			(2, 13),
+			(3, 12),
+			(4, 11),
+			(5, 10),
+			(6, 9),
+			(7, 8)
+		]
+	);
+	t.solve()?;
+	let (a, b) = t.child_nodes(*t.grand_finals())?;
+	let mut finalists = vec![t.winner(a)?.unwrap().0, t.winner(b)?.unwrap().0];
+	finalists.sort();
+	assert_eq!(finalists, vec![0, 1]);
+	Ok(())
+}
+
+fn entrant_depth(
+	t: &Tournament<IntFighter, String, IntBattleSystem>,
+	eid: EntrantId,
+) -> usize {
+	let mut node = t
+		.graph()
+		.node_indices()
+		.find(|n| t.graph()[*n].entrant() == Some(&eid))
+		.unwrap();
+	let mut depth = 0;
+	while let Some(parent) = t
+		.graph()
+		.neighbors_directed(node, Direction::Incoming)
+		.next()
+	{
+		node = parent;
+		depth += 1;
+	}
+	depth
+}
+
+#[test]
+fn byes_go_to_top_seeds() -> Result<()> {
+	for seeding in [Seeding::Standard, Seeding::Snake] {
+		let t = seeded_int_tournament(6, seeding)?;
+		let depths: Vec<usize> =
+			(0..6).map(|i| entrant_depth(&t, EntrantId(i))).collect();
+		assert_eq!(depths, vec![2, 2, 3, 3, 3, 3]);
+	}
+	Ok(())
+}
+
+#[test]
+fn byes_follow_the_seed_order() -> Result<()> {
+	let order = vec![
+		EntrantId(4),
+		EntrantId(2),
+		EntrantId(0),
+		EntrantId(3),
+		EntrantId(1),
+	];
+	let mut shuffled: Vec<EntrantId> = (0..5).map(EntrantId).collect();
+	shuffled.shuffle(&mut StdRng::seed_from_u64(7));
+	let mut rng = StdRng::seed_from_u64(7);
+	for (seeding, seeds) in [
+		(Seeding::Custom(order.clone()), order),
+		(Seeding::Random(&mut rng), shuffled),
+	] {
+		let t = seeded_int_tournament(5, seeding)?;
+		let depths: Vec<usize> =
+			seeds.iter().map(|eid| entrant_depth(&t, *eid)).collect();
+		assert_eq!(depths, vec![2, 2, 2, 3, 3]);
+	}
+	Ok(())
+}
+
+#[test]
+fn snake_seeding() -> Result<()> {
+	let mut t = seeded_int_tournament(8, Seeding::Snake)?;
+	assert_eq!(opening_rounds(&t)?, vec![(0, 7), (1, 6), (2, 5), (3, 4)]);
+	t.solve()?;
+	let (a, b) = t.child_nodes(*t.grand_finals())?;
+	let (a_semi, b_semi) = (t.child_nodes(a)?, t.child_nodes(b)?);
+	let mut semifinals = vec![];
+	for (a, b) in [a_semi, b_semi] {
+		let (a, b) = (t.winner(a)?.unwrap().0, t.winner(b)?.unwrap().0);
+		semifinals.push((a.min(b), a.max(b)));
+	}
+	semifinals.sort();
+	assert_eq!(semifinals, vec![(0, 3), (1, 2)]);
+	Ok(())
+}
+
+#[test]
+fn snake_top_seeds_meet_in_the_final() -> Result<()> {
+	for i in 2..100 {
+		let mut t = seeded_int_tournament(i, Seeding::Snake)?;
+		t.solve()?;
+		let (a, b) = t.child_nodes(*t.grand_finals())?;
+		let mut finalists = vec![t.winner(a)?.unwrap().0, t.winner(b)?.unwrap().0];
+		finalists.sort();
+		assert_eq!(finalists, vec![0, 1]);
+	}
+	Ok(())
+}
+
+#[test]
+fn custom_seeding() -> Result<()> {
+	let order = vec![EntrantId(3), EntrantId(0), EntrantId(2), EntrantId(1)];
+	let t = seeded_int_tournament(4, Seeding::Custom(order))?;
+	assert_eq!(opening_rounds(&t)?, vec![(0, 2), (1, 3)]);
+
+	let duplicate = vec![EntrantId(3), EntrantId(3), EntrantId(2), EntrantId(1)];
+	assert!(matches!(
+		seeded_int_tournament(4, Seeding::Custom(duplicate)),
+		Err(TournamentError::InvalidSeeding)
+	));
+	Ok(())
+}
+
+#[test]
+fn random_seeding() -> Result<()> {
+	let mut a = StdRng::seed_from_u64(42);
+	let mut b = StdRng::seed_from_u64(42);
+	let t_a = seeded_int_tournament(40, Seeding::Random(&mut a))?;
+	let t_b = seeded_int_tournament(40, Seeding::Random(&mut b))?;
+	assert_eq!(opening_rounds(&t_a)?, opening_rounds(&t_b)?);
+	assert_eq!(t_a.len_rounds(), 39);
+	Ok(())
+}
+
+/// Counts its battles. When `upsets` is set, the smaller number wins instead.
+struct CountingBattleSystem {
+	upsets: bool,
+	battles: usize,
+}
+impl BattleSystem<IntFighter, String> for CountingBattleSystem {
+	fn battle(
+		&mut self,
+		a_arc: Arc<RwLock<IntFighter>>,
+		b_arc: Arc<RwLock<IntFighter>>,
+		_: &mut dyn RngCore,
+	) -> BattleResult<String> {
+		use TournamentRoundResult::*;
+		self.battles += 1;
+		let a = a_arc.read().unwrap().0;
+		let b = b_arc.read().unwrap().0;
+		let winner = if (a > b) != self.upsets { A } else { B };
+		BattleResult::Solved(winner, format!("Battle #{}", self.battles))
+	}
+	fn tiebreaker(
+		&mut self,
+		_: Arc<RwLock<IntFighter>>,
+		_: Arc<RwLock<IntFighter>>,
+		_: &mut dyn RngCore,
+	) -> TiebreakerResult<String> {
+		Ok((TournamentRoundResult::A, String::new()))
+	}
+}
+
+#[test]
+fn stateful_battle_system() -> Result<()> {
+	let entrants: Vec<IntFighter> = (1..=10).map(IntFighter).collect();
+	let mut t = Tournament::new_with_battle_system(
+		entrants,
+		Seeding::Ordered,
+		CountingBattleSystem {
+			upsets: true,
+			battles: 0,
+		},
+	)?;
+	let (a, b) = t.child_nodes(*t.grand_finals())?;
+	t.solve_round(a)?;
+	t.solve_round(a)?;
+	assert_eq!(t.battle_system().battles, t.len_rounds_complete());
+
+	t.battle_system_mut().upsets = false;
+	t.solve_round(b)?;
+	t.solve()?;
+	assert_eq!(t.battle_system().battles, 9);
+	assert_eq!(
+		t.graph()[*t.grand_finals()].metadata(),
+		Some(&"Battle #9".to_string())
+	);
+	// The weakest of the half solved with upsets reaches the final.
+	let champion = t.winner_entrant(*t.grand_finals())?.unwrap();
+	assert_eq!(champion.read().unwrap().0, 6);
+	Ok(())
+}
+
+/// A battle system written against the original signatures. It's never created, so it needn't be `Clone`, `Default` or `Send`.
+#[allow(dead_code)]
+struct OldBattleSystem(std::rc::Rc<()>);
+impl StatelessBattleSystem<IntFighter, String> for OldBattleSystem {
+	fn battle(
+		a_arc: Arc<RwLock<IntFighter>>,
+		b_arc: Arc<RwLock<IntFighter>>,
+	) -> BattleResult<String> {
+		use TournamentRoundResult::*;
+		let a = a_arc.read().unwrap().0;
+		let b = b_arc.read().unwrap().0;
+		match a.cmp(&b) {
+			std::cmp::Ordering::Greater => BattleResult::Solved(A, format!("{}", a)),
+			std::cmp::Ordering::Less => BattleResult::Solved(B, format!("{}", b)),
+			std::cmp::Ordering::Equal => BattleResult::Tie,
+		}
+	}
+	fn tiebreaker(
+		_: Arc<RwLock<IntFighter>>,
+		_: Arc<RwLock<IntFighter>>,
+	) -> (TournamentRoundResult, String) {
+		(TournamentRoundResult::B, "Tiebreaker".to_string())
+	}
+}
+
+#[test]
+fn stateless_battle_system() -> Result<()> {
+	let entrants: Vec<IntFighter> =
+		[3, 5, 4, 1, 1].iter().map(|n| IntFighter(*n)).collect();
+	let mut t =
+		Tournament::<IntFighter, String, Stateless<OldBattleSystem>>::new(
+			entrants,
+		)?;
+	t.solve()?;
+	assert_eq!(t.winner(*t.grand_finals())?, Some(EntrantId(1)));
+	assert_eq!(
+		t.graph()[*t.grand_finals()].metadata(),
+		Some(&"5".to_string())
+	);
+	// The two 1s tie, and the tiebreaker always picks side B.
+	let tied = t
+		.graph()
+		.node_indices()
+		.find(|id| t.graph()[*id].metadata() == Some(&"Tiebreaker".to_string()))
+		.unwrap();
+	assert_eq!(t.graph()[tied].result(), Some(&TournamentRoundResult::B));
+
+	#[cfg(feature = "parallel")]
+	{
+		let entrants = (1..=5).map(IntFighter).collect();
+		let mut t =
+			Tournament::<IntFighter, String, Stateless<OldBattleSystem>>::new(
+				entrants,
+			)?;
+		t.solve_parallel()?;
+		assert_eq!(t.winner(*t.grand_finals())?, Some(EntrantId(4)));
+	}
+	Ok(())
+}
+
+/// Every round's metadata, in node order.
+fn round_metadata(
+	t: &Tournament<IntFighter, String, IntBattleSystem>,
+) -> Vec<Option<String>> {
+	t.graph()
+		.node_indices()
+		.map(|i| t.graph()[i].metadata().cloned())
+		.collect()
+}
+
+#[test]
+fn reproducible_from_seed() -> Result<()> {
+	let run = |seed: u64| -> Result<_> {
+		let mut rng = StdRng::seed_from_u64(seed);
+		// Lots of ties, so that results depend on the tiebreaker.
+		let entrants = (0..50).map(|i| IntFighter(i % 3)).collect();
+		let mut t = Tournament::<IntFighter, String, IntBattleSystem>::new_seeded(
+			entrants,
+			Seeding::Random(&mut rng),
+		)?;
+		t.set_rng(rng);
+		t.solve()?;
+		Ok((opening_rounds(&t)?, round_metadata(&t)))
+	};
+	assert_eq!(run(7)?, run(7)?);
+	assert_ne!(run(7)?, run(8)?);
+	Ok(())
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn solve_parallel() -> Result<()> {
+	let mut sequential = random_int_tournament(300)?;
+	sequential.solve()?;
+
+	let log = Arc::new(RwLock::new(vec![]));
+	let mut t = random_int_tournament(300)?;
+	t.add_observer(RecordingObserver(log.clone()));
+	let (a, _) = t.child_nodes(*t.grand_finals())?;
+	t.solve_round(a)?;
+	let solved_first = t.len_rounds_complete();
+	t.solve_parallel()?;
+	assert_eq!(round_metadata(&t), round_metadata(&sequential));
+	assert_eq!(
+		t.winner(*t.grand_finals())?,
+		sequential.winner(*t.grand_finals())?
+	);
+
+	// Every round sends its events exactly once, whichever way it was solved.
+	let events = log.read().unwrap().clone();
+	let starts = events
+		.iter()
+		.filter(|e| matches!(e, Event::Start(..)))
+		.count();
+	assert_eq!(starts, t.len_rounds());
+	assert!(solved_first > 0 && solved_first < t.len_rounds());
+	assert_eq!(
+		events.last(),
+		Some(&Event::Champion(t.winner(*t.grand_finals())?.unwrap()))
+	);
+
+	assert!(matches!(
+		t.solve_round_parallel(NodeIndex::new(1_000_000)),
+		Err(TournamentError::RoundNotFound(_))
+	));
+	Ok(())
+}
+
+#[test]
+fn large_bracket() -> Result<()> {
+	let len = 100_000;
+	let mut t = Tournament::<IntFighter, String, IntBattleSystem>::new(
+		(0..len as u32).map(IntFighter).collect(),
+	)?;
+	assert_eq!(t.len_rounds(), len - 1);
+	t.solve()?;
+	assert_eq!(t.len_rounds_incomplete(), 0);
+	assert_eq!(t.winner(*t.grand_finals())?, Some(EntrantId(len - 1)));
+	Ok(())
+}
+
+#[test]
+fn solve_step_by_step() -> Result<()> {
+	let mut t = winner_127_tournament()?;
+	let total = t.len_rounds();
+	let mut solved = 0;
+	while let Some(id) = t.solve_next()? {
+		assert!(t.graph()[id].result().is_some());
+		solved += 1;
+		assert_eq!(t.len_rounds_complete(), solved);
+	}
+	assert_eq!(solved, total);
+	assert!(t.ready_rounds()?.is_empty());
+	let champion = t.winner_entrant(*t.grand_finals())?.unwrap();
+	assert_eq!(champion.read().unwrap().0, 127);
+	Ok(())
+}
+
+#[test]
+fn ready_rounds() -> Result<()> {
+	let mut t = random_int_tournament(8)?;
+	assert_eq!(t.ready_rounds()?.len(), 4);
+	let first = t.solve_next()?.unwrap();
+	assert_eq!(t.ready_rounds()?.len(), 3);
+	assert!(!t.ready_rounds()?.contains(&first));
+	for id in t.ready_rounds()? {
+		let (a, b) = t.child_nodes(id)?;
+		assert!(t.winner(a)?.is_some());
+		assert!(t.winner(b)?.is_some());
+	}
+	Ok(())
+}
+
+#[test]
+fn solve_layer() -> Result<()> {
+	let mut t = random_int_tournament(10)?;
+	let mut layers = vec![];
+	loop {
+		let ready = t.ready_rounds()?;
+		let layer = t.solve_layer()?;
+		if layer.is_empty() {
+			break;
+		}
+		assert!(layer.iter().all(|id| ready.contains(id)));
+		layers.push(layer.len());
+	}
+	assert_eq!(layers, vec![2, 4, 2, 1]);
+	assert!(t.solve_next()?.is_none());
+	assert_eq!(t.len_rounds_incomplete(), 0);
+	Ok(())
+}
+
+#[test]
+fn report_result() -> Result<()> {
+	use TournamentRoundResult::*;
+	let mut t = winner_127_tournament()?;
+	let grand_finals = *t.grand_finals();
+	assert!(matches!(
+		t.report_result(grand_finals, A, String::new()),
+		Err(TournamentError::RoundNotReady(id)) if id == grand_finals
+	));
+
+	// The weaker entrant wins every opening round, as reported by hand.
+	let mut opening = t.ready_rounds()?;
+	opening.sort();
+	for id in opening.iter().copied() {
+		let (a, b) = t.child_nodes(id)?;
+		let value =
+			|node| t.winner_entrant(node).map(|e| e.unwrap().read().unwrap().0);
+		let (result, loser) = if value(a)? < value(b)? {
+			(A, b)
+		} else {
+			(B, a)
+		};
+		let winner = t.report_result(id, result, "Reported".to_string())?;
+		assert_eq!(Some(winner), t.winner(id)?);
+		assert_ne!(t.winner(loser)?, Some(winner));
+		assert!(matches!(
+			t.report_result(id, result, String::new()),
+			Err(TournamentError::RoundAlreadyComplete(_))
+		));
+		assert!(matches!(
+			t.report_result(a, A, String::new()),
+			Err(TournamentError::NotARound(node)) if node == a
+		));
+	}
+	assert!(matches!(
+		t.report_result(NodeIndex::new(1000), A, String::new()),
+		Err(TournamentError::RoundNotFound(_))
+	));
+
+	// The rest of the bracket is simulated.
+	t.solve()?;
+	assert_eq!(t.len_rounds_incomplete(), 0);
+	let reported: Vec<NodeIndex> = t
+		.graph()
+		.node_indices()
+		.filter(|id| t.graph()[*id].metadata() == Some(&"Reported".to_string()))
+		.collect();
+	assert_eq!(reported, opening);
+	Ok(())
+}
+
+#[test]
+fn errors() -> Result<()> {
+	use std::error::Error;
+
+	let mut t = winner_127_tournament()?;
+	let (a, _) = t.child_nodes(*t.grand_finals())?;
+	let entrant = t
+		.graph()
+		.node_indices()
+		.find(|id| t.graph()[*id].entrant().is_some())
+		.unwrap();
+	let err = t.solve_round(entrant).unwrap_err();
+	assert!(matches!(err, TournamentError::NotARound(id) if id == entrant));
+	assert_eq!(
+		err.to_string(),
+		format!("node {} is not a round", entrant.index())
+	);
+	assert!(err.source().is_none());
+
+	// Poison the lock around an entrant by panicking while holding it.
+	let arc = t.entrant(EntrantId(3));
+	let _ = std::thread::spawn(move || {
+		let _guard = arc.write().unwrap();
+		panic!("poisoned on purpose");
+	})
+	.join();
+	assert!(matches!(
+		t.to_svg(),
+		Err(TournamentError::EntrantLockPoisoned(EntrantId(3)))
+	));
+	assert_eq!(
+		TournamentError::EntrantLockPoisoned(EntrantId(3)).to_string(),
+		"the lock around Entrant #3 is poisoned"
+	);
+
+	let battle = TournamentError::BattleFailed(a, "simulator timed out".into());
+	assert_eq!(
+		battle.to_string(),
+		format!(
+			"battle system failed in round {}: simulator timed out",
+			a.index()
+		)
+	);
+	assert_eq!(battle.source().unwrap().to_string(), "simulator timed out");
+	Ok(())
+}
+
+/// The larger number wins, and ties always fail. The battle numbered `fail_at` also fails, after tampering with entrant `A`.
+pub(crate) struct FlakyBattleSystem {
+	pub battles: usize,
+	pub fail_at: usize,
+}
+impl BattleSystem<IntFighter, String> for FlakyBattleSystem {
+	fn battle(
+		&mut self,
+		a_arc: Arc<RwLock<IntFighter>>,
+		b_arc: Arc<RwLock<IntFighter>>,
+		_: &mut dyn RngCore,
+	) -> BattleResult<String> {
+		use TournamentRoundResult::*;
+		self.battles += 1;
+		if self.battles == self.fail_at {
+			a_arc.write().unwrap().0 += 1000;
+			return BattleResult::Failed("simulator timed out".into());
+		}
+		let a = a_arc.read().unwrap().0;
+		let b = b_arc.read().unwrap().0;
+		if a == b {
+			return BattleResult::Tie;
+		}
+		BattleResult::Solved(if a > b { A } else { B }, String::new())
+	}
+	fn tiebreaker(
+		&mut self,
+		_: Arc<RwLock<IntFighter>>,
+		_: Arc<RwLock<IntFighter>>,
+		_: &mut dyn RngCore,
+	) -> TiebreakerResult<String> {
+		Err("no tiebreakers".into())
+	}
+}
+
+#[test]
+fn failing_battle_system() -> Result<()> {
+	use std::error::Error;
+
+	let entrants: Vec<IntFighter> = (1..=8).map(IntFighter).collect();
+	let mut t = Tournament::new_with_battle_system(
+		entrants,
+		Seeding::Ordered,
+		FlakyBattleSystem {
+			battles: 0,
+			fail_at: 3,
+		},
+	)?;
+	t.set_snapshots(true);
+	let before: Vec<u32> = (0..t.len_entrants())
+		.map(|i| t.entrant(EntrantId(i)).read().unwrap().0)
+		.collect();
+
+	let err = t.solve().unwrap_err();
+	let failed = match &err {
+		TournamentError::BattleFailed(id, _) => *id,
+		_ => panic!("expected BattleFailed, got {:?}", err),
+	};
+	assert_eq!(err.source().unwrap().to_string(), "simulator timed out");
+	// The two rounds solved before the failure keep their results, and nothing else is solved.
+	assert_eq!(t.len_rounds_complete(), 2);
+	assert_eq!(t.winner(failed)?, None);
+	assert_eq!(t.winner(*t.grand_finals())?, None);
+	// The failed round's tampering is undone.
+	for (i, value) in before.iter().enumerate() {
+		assert_eq!(t.entrant(EntrantId(i)).read().unwrap().0, *value);
+	}
+
+	// Solving again picks up from the failed round.
+	t.solve()?;
+	assert_eq!(t.battle_system().battles, 8);
+	assert_eq!(t.len_rounds_incomplete(), 0);
+	assert_eq!(
+		t.winner_entrant(*t.grand_finals())?
+			.unwrap()
+			.read()
+			.unwrap()
+			.0,
+		8
+	);
+
+	// A failing tiebreaker fails the round too.
+	let mut t = Tournament::new_with_battle_system(
+		vec![IntFighter(5), IntFighter(5)],
+		Seeding::Ordered,
+		FlakyBattleSystem {
+			battles: 0,
+			fail_at: 0,
+		},
+	)?;
+	let err = t.solve().unwrap_err();
+	assert!(
+		matches!(err, TournamentError::BattleFailed(id, _) if id == *t.grand_finals())
+	);
+	assert_eq!(err.source().unwrap().to_string(), "no tiebreakers");
+	assert_eq!(t.len_rounds_complete(), 0);
+	Ok(())
+}
+
+/// The round entrant `eid` plays first, skipping any bye.
+fn first_round(
+	graph: &Graph<TournamentNode<String>, TournamentEdge>,
+	eid: EntrantId,
+) -> NodeIndex {
+	let node = graph
+		.node_indices()
+		.find(|id| graph[*id].entrant() == Some(&eid))
+		.unwrap();
+	graph
+		.neighbors_directed(node, Direction::Incoming)
+		.next()
+		.unwrap()
+}
+
+#[test]
+fn withdraw() -> Result<()> {
+	let entrants: Vec<IntFighter> = (1..=8).map(IntFighter).collect();
+	let mut t = Tournament::new_with_battle_system(
+		entrants,
+		Seeding::Ordered,
+		CountingBattleSystem {
+			upsets: false,
+			battles: 0,
+		},
+	)?;
+	assert!(matches!(
+		t.withdraw(EntrantId(8)),
+		Err(TournamentError::EntrantNotFound(EntrantId(8)))
+	));
+	t.withdraw(EntrantId(7))?;
+	t.withdraw(EntrantId(7))?;
+	assert!(t.is_withdrawn(EntrantId(7)));
+	assert_eq!(t.withdrawn(), &vec![EntrantId(7)]);
+
+	t.solve()?;
+	// The strongest entrant withdrew, so their round is a walkover and never reaches the battle system.
+	assert_eq!(t.battle_system().battles, 6);
+	let forfeited = first_round(t.graph(), EntrantId(7));
+	assert_eq!(
+		t.graph()[forfeited].decision(),
+		Some(RoundDecision::Walkover)
+	);
+	assert_eq!(t.graph()[forfeited].metadata(), Some(&String::new()));
+	assert_eq!(t.loser(forfeited)?, Some(EntrantId(7)));
+	assert!(t.graph()[forfeited]
+		.round()
+		.unwrap()
+		.to_string()
+		.contains("by walkover"));
+	assert_eq!(
+		t.graph()[*t.grand_finals()].decision(),
+		Some(RoundDecision::Played)
+	);
+	assert_eq!(t.winner(*t.grand_finals())?, Some(EntrantId(6)));
+	Ok(())
+}
+
+#[test]
+fn disqualify() -> Result<()> {
+	let mut t = winner_127_tournament()?;
+	t.solve()?;
+	let champion = t.winner(*t.grand_finals())?.unwrap();
+
+	// Overturn every round the champion won.
+	t.withdraw(champion)?;
+	t.revert_round(first_round(t.graph(), champion))?;
+	t.solve()?;
+	assert_ne!(t.winner(*t.grand_finals())?, Some(champion));
+	for id in t.graph().node_indices() {
+		if t.loser(id)? == Some(champion) {
+			assert_eq!(t.graph()[id].decision(), Some(RoundDecision::Walkover));
+		}
+	}
+
+	// When both sides have withdrawn, `A` advances.
+	let mut t = Tournament::<IntFighter, String, IntBattleSystem>::new(vec![
+		IntFighter(1),
+		IntFighter(2),
+	])?;
+	t.withdraw(EntrantId(0))?;
+	t.withdraw(EntrantId(1))?;
+	assert_eq!(t.solve_round(*t.grand_finals())?, TournamentRoundResult::A);
+	Ok(())
+}
+
+/// Sides take turns winning, starting with `A`, so that every series goes the distance.
+struct AlternatingBattleSystem(usize);
+impl BattleSystem<IntFighter, String> for AlternatingBattleSystem {
+	fn battle(
+		&mut self,
+		_: Arc<RwLock<IntFighter>>,
+		_: Arc<RwLock<IntFighter>>,
+		_: &mut dyn RngCore,
+	) -> BattleResult<String> {
+		use TournamentRoundResult::*;
+		self.0 += 1;
+		let winner = if self.0 % 2 == 1 { A } else { B };
+		BattleResult::Solved(winner, format!("Game #{}", self.0))
+	}
+	fn tiebreaker(
+		&mut self,
+		_: Arc<RwLock<IntFighter>>,
+		_: Arc<RwLock<IntFighter>>,
+		_: &mut dyn RngCore,
+	) -> TiebreakerResult<String> {
+		Ok((TournamentRoundResult::A, String::new()))
+	}
+}
+
+#[test]
+fn best_of_series() -> Result<()> {
+	let entrants: Vec<IntFighter> = (1..=8).map(IntFighter).collect();
+	let mut t = Tournament::new_with_battle_system(
+		entrants,
+		Seeding::Ordered,
+		AlternatingBattleSystem(0),
+	)?;
+	let gf = *t.grand_finals();
+	let (semi, _) = t.child_nodes(gf)?;
+	let (quarter, _) = t.child_nodes(semi)?;
+	assert!(matches!(
+		t.set_best_of(2),
+		Err(TournamentError::InvalidBestOf(2))
+	));
+	assert!(matches!(
+		t.set_best_of_round(t.child_node(quarter, TournamentEdge::A)?, 3),
+		Err(TournamentError::NotARound(_))
+	));
+	t.set_best_of(3)?;
+	t.set_best_of_depth(1, 5)?;
+	t.set_best_of_round(gf, 9)?;
+	t.set_best_of_round(gf, 7)?;
+	assert_eq!(t.best_of(quarter)?, 3);
+	assert_eq!(t.best_of(semi)?, 5);
+	assert_eq!(t.best_of(gf)?, 7);
+
+	t.solve()?;
+	assert_eq!(t.battle_system().0, 4 * 3 + 2 * 5 + 7);
+	assert_eq!(t.graph()[quarter].series().map(Vec::len), Some(3));
+	assert_eq!(t.graph()[semi].series().map(Vec::len), Some(5));
+	let finals = t.graph()[gf].round().unwrap();
+	assert_eq!(finals.score(), Some((4, 3)));
+	assert_eq!(finals.metadata(), Some(&"Game #29".to_string()));
+	assert_eq!(finals.to_string(), "A wins 4-3 --- Game #29");
+	let games: Vec<_> =
+		finals.series().unwrap().iter().map(|g| g.result).collect();
+	assert_eq!(
+		games[..2],
+		[TournamentRoundResult::A, TournamentRoundResult::B]
+	);
+
+	// A series that fails partway through is left incomplete, without any of its games. The failed game leaves side `A` stronger.
+	let entrants: Vec<IntFighter> = (1..=4).map(IntFighter).collect();
+	let mut t = Tournament::new_with_battle_system(
+		entrants,
+		Seeding::Ordered,
+		FlakyBattleSystem {
+			battles: 0,
+			fail_at: 2,
+		},
+	)?;
+	t.set_best_of(3)?;
+	let (a, _) = t.child_nodes(*t.grand_finals())?;
+	assert!(t.solve_round(a).is_err());
+	assert!(t.graph()[a].series().is_none());
+	t.solve()?;
+	assert_eq!(t.graph()[a].round().unwrap().score(), Some((2, 0)));
+	Ok(())
+}
+
+#[test]
+fn revert_round() -> Result<()> {
+	let mut t = winner_127_tournament()?;
+	let opening = t.ready_rounds()?;
+	t.solve()?;
+	let champion = t.winner(*t.grand_finals())?;
+	let id = opening[0];
+	assert!(matches!(
+		t.revert_round(t.child_nodes(id)?.0),
+		Err(TournamentError::NotARound(_))
+	));
+
+	// Every round from the opening round up to the grand finals is reset.
+	let mut reverted = t.revert_round(id)?;
+	reverted.sort();
+	let mut expected = vec![id];
+	while let Some(parent) = t
+		.graph()
+		.neighbors_directed(*expected.last().unwrap(), Direction::Incoming)
+		.next()
+	{
+		expected.push(parent);
+	}
+	expected.sort();
+	assert_eq!(reverted, expected);
+	assert!(reverted.contains(t.grand_finals()));
+	assert_eq!(t.len_rounds_incomplete(), reverted.len());
+	for id in reverted.iter() {
+		assert_eq!(t.winner(*id)?, None);
+	}
+	assert!(t.revert_round(id)?.is_empty());
+
+	t.solve()?;
+	assert_eq!(t.winner(*t.grand_finals())?, champion);
+	assert_eq!(t.len_rounds_incomplete(), 0);
+	Ok(())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Event {
+	Start(NodeIndex, EntrantId, EntrantId),
+	Tie(NodeIndex),
+	Complete(NodeIndex, TournamentRoundResult),
+	Champion(EntrantId),
+}
+
+/// Records every event it receives into a shared log.
+pub(crate) struct RecordingObserver(pub Arc<RwLock<Vec<Event>>>);
+impl TournamentObserver<String> for RecordingObserver {
+	fn on_round_start(&mut self, node: NodeIndex, a: EntrantId, b: EntrantId) {
+		self.0.write().unwrap().push(Event::Start(node, a, b));
+	}
+	fn on_tie(&mut self, node: NodeIndex) {
+		self.0.write().unwrap().push(Event::Tie(node));
+	}
+	fn on_round_complete(
+		&mut self,
+		node: NodeIndex,
+		result: TournamentRoundResult,
+		_: &String,
+	) {
+		self.0.write().unwrap().push(Event::Complete(node, result));
+	}
+	fn on_tournament_complete(&mut self, winner: EntrantId) {
+		self.0.write().unwrap().push(Event::Champion(winner));
+	}
+}
+
+#[test]
+fn observer() -> Result<()> {
+	let log = Arc::new(RwLock::new(vec![]));
+	let mut t = Tournament::<IntFighter, String, IntBattleSystem>::new(vec![
+		IntFighter(1),
+		IntFighter(1),
+		IntFighter(2),
+		IntFighter(3),
+		IntFighter(3),
+	])?;
+	t.add_observer(RecordingObserver(log.clone()));
+	t.solve()?;
+
+	let events = log.read().unwrap().clone();
+	let champion = t.winner(*t.grand_finals())?.unwrap();
+	assert_eq!(events.last(), Some(&Event::Champion(champion)));
+	let mut ties = vec![];
+	let mut rounds = 0;
+	for pair in events.windows(2) {
+		match pair {
+			// The tie comes after the round starts, and before it completes.
+			[Event::Start(id, a, b), next] => {
+				rounds += 1;
+				let value = |eid: &EntrantId| t.entrant(*eid).read().unwrap().0;
+				if value(a) == value(b) {
+					ties.push(*id);
+					assert_eq!(next, &Event::Tie(*id));
+				} else {
+					assert_eq!(
+						next,
+						&Event::Complete(*id, t.graph()[*id].result().copied().unwrap())
+					);
+				}
+			}
+			[Event::Tie(id), next] => {
+				assert!(matches!(next, Event::Complete(node, _) if node == id))
+			}
+			_ => {}
+		}
+	}
+	assert_eq!(rounds, t.len_rounds());
+	assert_eq!(ties.len(), 2);
+
+	// Reported results are sent too, and solved rounds aren't sent again.
+	t.revert_round(*t.grand_finals())?;
+	log.write().unwrap().clear();
+	t.report_result(*t.grand_finals(), TournamentRoundResult::A, String::new())?;
+	t.solve()?;
+	let champion = t.winner(*t.grand_finals())?.unwrap();
+	assert_eq!(
+		*log.read().unwrap(),
+		vec![
+			Event::Complete(*t.grand_finals(), TournamentRoundResult::A),
+			Event::Champion(champion),
+		]
+	);
+	Ok(())
+}
+
+/// Sends the champion down a channel, counting rounds in a `Cell`, so it's `Send` but not `Sync`.
+struct ChannelObserver {
+	sender: std::sync::mpsc::Sender<EntrantId>,
+	rounds: std::cell::Cell<usize>,
+}
+impl TournamentObserver<String> for ChannelObserver {
+	fn on_round_complete(
+		&mut self,
+		_: NodeIndex,
+		_: TournamentRoundResult,
+		_: &String,
+	) {
+		self.rounds.set(self.rounds.get() + 1);
+	}
+	fn on_tournament_complete(&mut self, winner: EntrantId) {
+		self.sender.send(winner).unwrap();
+	}
+}
+
+#[test]
+fn unsync_observer() -> Result<()> {
+	let (sender, receiver) = std::sync::mpsc::channel();
+	let mut t = random_int_tournament(9)?;
+	t.add_observer(ChannelObserver {
+		sender: sender.clone(),
+		rounds: std::cell::Cell::new(0),
+	});
+	t.solve()?;
+	assert_eq!(receiver.try_recv().ok(), t.winner(*t.grand_finals())?);
+
+	#[cfg(feature = "parallel")]
+	{
+		let mut t = random_int_tournament(9)?;
+		t.add_observer(ChannelObserver {
+			sender,
+			rounds: std::cell::Cell::new(0),
+		});
+		t.solve_parallel()?;
+		assert_eq!(receiver.try_recv().ok(), t.winner(*t.grand_finals())?);
+	}
+	Ok(())
+}
+
+#[test]
+fn to_dot() -> Result<()> {
+	let mut t = winner_127_tournament()?;
+	let dot = t.to_dot()?;
+	assert!(dot.starts_with("digraph tournament {\n"));
+	assert!(dot.ends_with("}\n"));
+	assert_eq!(dot.matches(" -> ").count(), t.graph().edge_count());
+	assert_eq!(dot.matches("Incomplete").count(), t.len_rounds());
+	assert!(!dot.contains("goldenrod"));
+	assert!(dot.contains("label=\"Int Fighter: 127\""));
+
+	t.solve()?;
+	let dot = t.to_dot()?;
+	assert!(!dot.contains("Incomplete"));
+	let grand_finals = t.grand_finals().index();
+	let gf_line = dot
+		.lines()
+		.find(|line| line.starts_with(&format!("\tn{} [", grand_finals)))
+		.unwrap();
+	assert!(gf_line.contains("Winner: Int Fighter: 127"));
+	assert!(gf_line.contains("goldenrod"));
+	// The champion's entrant node, every round they won and the edges between them.
+	let rounds_won = t
+		.graph()
+		.node_indices()
+		.filter(|id| t.graph()[*id].round().is_some())
+		.filter(|id| t.winner(*id).unwrap() == Some(EntrantId(6)))
+		.count();
+	assert_eq!(dot.matches("goldenrod").count(), rounds_won * 2 + 1);
+	let side_a = format!(
+		"\tn{} -> n{} [label=\"A\"",
+		grand_finals,
+		t.child_nodes(*t.grand_finals())?.0.index()
+	);
+	assert!(dot.contains(&side_a));
+	Ok(())
+}
+
+#[test]
+fn to_svg() -> Result<()> {
+	let mut t = winner_127_tournament()?;
+	let svg = t.to_svg()?;
+	assert!(svg.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\""));
+	assert!(svg.ends_with("</svg>\n"));
+	assert_eq!(svg.matches("<rect ").count(), t.graph().node_count());
+	assert_eq!(svg.matches("<path ").count(), t.graph().edge_count());
+	assert_eq!(svg.matches("stroke-dasharray").count(), t.len_rounds());
+	assert_eq!(svg.matches("<text ").count(), t.len_entrants());
+	assert!(!svg.contains("font-weight=\"bold\""));
+
+	t.solve()?;
+	let svg = t.to_svg()?;
+	assert!(!svg.contains("stroke-dasharray"));
+	assert_eq!(svg.matches("<title>").count(), t.len_rounds());
+	// One advancing entrant per round, plus the champion.
+	assert_eq!(
+		svg.matches("font-weight=\"bold\"").count(),
+		t.len_rounds() + 1
+	);
+	assert!(svg
+		.contains("font-weight=\"bold\">Int Fighter: 127</text>\n\t</g>\n</svg>"));
+
+	// The third place playoff is drawn below the bracket, linked only to the losers it's played between.
+	let mut t = winner_127_tournament()?.with_third_place_playoff()?;
+	t.solve()?;
+	let svg = t.to_svg()?;
+	assert_eq!(svg.matches("<rect ").count(), t.graph().node_count());
+	assert_eq!(svg.matches("<path ").count(), t.graph().edge_count() - 2);
+	assert_eq!(svg.matches("<title>").count(), t.len_rounds());
+	let third = t.winner(*t.third_place().unwrap())?.unwrap();
+	assert!(svg.ends_with(&format!(
+		"font-weight=\"bold\">{}</text>\n\t</g>\n</svg>\n",
+		t.entrant_name(third)?
+	)));
+	Ok(())
+}
+
+#[test]
+fn to_html() -> Result<()> {
+	let mut t = winner_127_tournament()?;
+	let html = t.to_html()?;
+	assert!(html.starts_with("<!DOCTYPE html>\n"));
+	assert!(html.ends_with("</html>\n"));
+	// No external assets.
+	assert!(!html.contains("src=") && !html.contains("href="));
+	assert_eq!(html.matches("Undecided").count(), 3);
+	assert_eq!(html.matches("Not played yet").count(), t.len_rounds());
+	assert!(html.contains(t.to_svg()?.trim_end()));
+
+	t.solve()?;
+	let html = t.to_html()?;
+	assert!(html.contains("<tr><th>Champion</th><td>Int Fighter: 127</td></tr>"));
+	let runner_up = t.loser(*t.grand_finals())?.unwrap();
+	assert!(html.contains(&format!(
+		"<tr><th>Runner-up</th><td>{}</td></tr>",
+		t.entrant(runner_up).read().unwrap()
+	)));
+	assert!(html.contains("<th>Semifinalists</th>"));
+	for name in ["Final", "Semifinals", "Quarterfinals", "Round of 16"].iter() {
+		assert!(html.contains(&format!("<h3>{}</h3>", name)));
+	}
+	// Every round lists its winner in bold.
+	assert_eq!(
+		html.matches("<span class=\"winner\">").count(),
+		t.len_rounds()
+	);
+	Ok(())
+}
+
+#[test]
+fn display() -> Result<()> {
+	let mut t = Tournament::<IntFighter, String, IntBattleSystem>::new(
+		(1..=4).map(IntFighter).collect(),
+	)?;
+	t.solve_next()?;
+	assert_eq!(
+		format!("{}", t),
+		"Int Fighter: 4 ─┐
+                ├─ Int Fighter: 4 ─┐
+Int Fighter: 3 ─┘                  │
+                                   ├─ TBD
+Int Fighter: 2 ─┐                  │
+                ├─ TBD            ─┘
+Int Fighter: 1 ─┘"
+	);
+
+	t.solve()?;
+	assert_eq!(
+		format!("{:#8}", t),
+		"Int Fig~ -+
+          +- Int Fig~ -+
+Int Fig~ -+            |
+                       +- Int Fig~
+Int Fig~ -+            |
+          +- Int Fig~ -+
+Int Fig~ -+"
+	);
+	assert_eq!(format!("{:.5}", t).lines().next(), Some("Int … ─┐"));
+	// With both, names are truncated to the precision within columns of the full width.
+	assert_eq!(
+		format!("{:#10.5}", t),
+		"Int ~      -+
+            +- Int ~      -+
+Int ~      -+              |
+                           +- Int ~
+Int ~      -+              |
+            +- Int ~      -+
+Int ~      -+"
+	);
+
+	// Entrants with a bye are connected straight to their first round.
+	let t = Tournament::<IntFighter, String, IntBattleSystem>::new(
+		(1..=3).map(IntFighter).collect(),
+	)?;
+	assert_eq!(
+		t.to_string(),
+		"Int Fighter: 1 ────────────────────┐
+                                   ├─ TBD
+Int Fighter: 3 ─┐                  │
+                ├─ TBD            ─┘
+Int Fighter: 2 ─┘"
+	);
+	Ok(())
+}
+
+#[test]
+fn third_place_playoff() -> Result<()> {
+	for size in [1, 3].iter() {
+		let entrants: Vec<IntFighter> = (1..=*size).map(IntFighter).collect();
+		assert!(matches!(
+			Tournament::<IntFighter, String, IntBattleSystem>::new(entrants)?
+				.with_third_place_playoff(),
+			Err(TournamentError::NeedsAtLeastFourEntrants)
+		));
+	}
+
+	let entrants: Vec<IntFighter> = (1..=8).map(IntFighter).collect();
+	let mut t = Tournament::new_with_battle_system(
+		entrants,
+		Seeding::Ordered,
+		CountingBattleSystem {
+			upsets: false,
+			battles: 0,
+		},
+	)?
+	.with_third_place_playoff()?
+	.with_third_place_playoff()?;
+	let third_place = *t.third_place().unwrap();
+	assert_eq!(t.len_rounds(), 8);
+	assert!(!t.ready_rounds()?.contains(&third_place));
+	let (semi_a, semi_b) = t.child_nodes(*t.grand_finals())?;
+	t.solve_round(semi_a)?;
+	t.solve_round(semi_b)?;
+	// The playoff is played just before the grand finals.
+	assert_eq!(t.ready_rounds()?, vec![third_place, *t.grand_finals()]);
+	assert_eq!(t.solve_layer()?, vec![third_place, *t.grand_finals()]);
+	assert_eq!(t.battle_system().battles, 8);
+	assert_eq!(t.winner(third_place)?, Some(EntrantId(5)));
+	assert_eq!(t.loser(third_place)?, Some(EntrantId(1)));
+	assert_eq!(t.winner(*t.grand_finals())?, Some(EntrantId(7)));
+
+	// Replaying a semifinal replays the playoff too.
+	assert!(t.revert_round(semi_a)?.contains(&third_place));
+	t.solve()?;
+	assert_eq!(t.winner(third_place)?, Some(EntrantId(5)));
+
+	// The playoff is at the semifinals' depth, so a longer grand finals stays its own.
+	t.set_best_of_depth(0, 7)?;
+	assert_eq!(t.best_of(*t.grand_finals())?, 7);
+	assert_eq!(t.best_of(third_place)?, 1);
+	t.set_best_of_depth(1, 3)?;
+	assert_eq!(t.best_of(semi_a)?, 3);
+	assert_eq!(t.best_of(third_place)?, 3);
+
+	let mut t = Tournament::<IntFighter, String, IntBattleSystem>::new(
+		(1..=4).map(IntFighter).collect(),
+	)?
+	.with_third_place_playoff()?;
+	t.solve()?;
+	assert_eq!(
+		format!("{:#8}", t),
+		"Int Fig~ -+
+          +- Int Fig~ -+
+Int Fig~ -+            |
+                       +- Int Fig~
+Int Fig~ -+            |
+          +- Int Fig~ -+
+Int Fig~ -+
+
+Int Fig~ -+
+          +- Int Fig~
+Int Fig~ -+"
+	);
+	let mut out = vec![];
+	let config = PrintConfig {
+		ansi: false,
+		metadata: false,
+		..PrintConfig::default()
+	};
+	crate::write_tournament(&t, &mut out, &config)?;
+	let out = String::from_utf8(out).unwrap();
+	// The playoff is printed after the bracket, without repeating the semifinals.
+	assert!(out.ends_with(
+		"└─ Int Fighter: 1
+Int Fighter: 3
+├─ Int Fighter: 1 (Loser)
+└─ Int Fighter: 3 (Loser)
+"
+	));
+
+	let html = t.to_html()?;
+	assert!(html.contains("<tr><th>Third place</th><td>Int Fighter: 3</td></tr>"));
+	assert!(
+		html.contains("<tr><th>Fourth place</th><td>Int Fighter: 1</td></tr>")
+	);
+	assert!(html.contains("<h3>Third place playoff</h3>"));
+
+	#[cfg(feature = "parallel")]
+	{
+		let mut parallel = Tournament::<IntFighter, String, IntBattleSystem>::new(
+			(1..=4).map(IntFighter).collect(),
+		)?
+		.with_third_place_playoff()?;
+		parallel.solve_parallel()?;
+		assert_eq!(round_metadata(&parallel), round_metadata(&t));
+	}
+	Ok(())
+}
+
+/// Counts how many times the tournament was completed.
+struct CompletionCounter(Arc<RwLock<usize>>);
+impl TournamentObserver<String> for CompletionCounter {
+	fn on_tournament_complete(&mut self, _: EntrantId) {
+		*self.0.write().unwrap() += 1;
+	}
+}
+
+#[test]
+fn third_place_playoff_completes_once() -> Result<()> {
+	let new = || -> Result<_> {
+		let count = Arc::new(RwLock::new(0));
+		let mut t = Tournament::<IntFighter, String, IntBattleSystem>::new(
+			(1..=8).map(IntFighter).collect(),
+		)?
+		.with_third_place_playoff()?;
+		t.add_observer(CompletionCounter(count.clone()));
+		Ok((t, count))
+	};
+
+	// Solving the playoff after the grand finals doesn't complete the tournament again.
+	let (mut t, count) = new()?;
+	t.solve_round(*t.grand_finals())?;
+	assert_eq!(*count.read().unwrap(), 1);
+	t.solve_round(*t.third_place().unwrap())?;
+	t.solve_round(*t.grand_finals())?;
+	assert_eq!(*count.read().unwrap(), 1);
+
+	// Neither does reporting it.
+	let (mut t, count) = new()?;
+	let third_place = *t.third_place().unwrap();
+	t.solve_round(*t.grand_finals())?;
+	t.report_result(third_place, TournamentRoundResult::A, String::new())?;
+	assert_eq!(*count.read().unwrap(), 1);
+
+	#[cfg(feature = "parallel")]
+	{
+		let (mut t, count) = new()?;
+		t.solve_round_parallel(*t.grand_finals())?;
+		t.solve_round_parallel(*t.third_place().unwrap())?;
+		assert_eq!(*count.read().unwrap(), 1);
+	}
+	Ok(())
+}
+
+/// A writer that always fails.
+struct BrokenWriter;
+impl std::io::Write for BrokenWriter {
+	fn write(&mut self, _: &[u8]) -> std::io::Result<usize> {
+		Err(std::io::Error::new(
+			std::io::ErrorKind::BrokenPipe,
+			"broken",
+		))
+	}
+	fn flush(&mut self) -> std::io::Result<()> {
+		Ok(())
+	}
+}
+
+#[test]
+fn write_tournament() -> Result<()> {
+	let write = |t: &Tournament<IntFighter, String, IntBattleSystem>,
+	             config: &PrintConfig|
+	 -> Result<String> {
+		let mut out = vec![];
+		crate::write_tournament(t, &mut out, config)?;
+		Ok(String::from_utf8(out).unwrap())
+	};
+	let mut t = winner_127_tournament()?;
+	let out = write(&t, &PrintConfig::default())?;
+	assert_eq!(out.lines().count(), t.graph().node_count());
+	assert_eq!(out.matches("Incomplete").count(), t.len_rounds());
+
+	t.solve()?;
+	let out = write(&t, &PrintConfig::default())?;
+	assert!(out.starts_with(
+		"Int Fighter: 127 (A wins --- Int Fighter: 127 wins by 118!)\n├─ Int Fighter: 127 ("
+	));
+	assert!(!out.contains('\x1b'));
+	let out = write(
+		&t,
+		&PrintConfig {
+			metadata: false,
+			..PrintConfig::default()
+		},
+	)?;
+	assert!(out.starts_with("Int Fighter: 127\n"));
+	assert!(!out.contains("wins by"));
+
+	let colored = PrintConfig {
+		entrant_color: Some(colored::Color::Green),
+		metadata_color: Some(colored::Color::Blue),
+		..PrintConfig::default()
+	};
+	let out = write(&t, &colored)?;
+	assert!(out.starts_with("\x1b[32mInt Fighter: 127\x1b[0m (\x1b[34mA wins"));
+	let plain = write(
+		&t,
+		&PrintConfig {
+			ansi: false,
+			..colored.clone()
+		},
+	)?;
+	assert_eq!(plain, write(&t, &PrintConfig::default())?);
+
+	// Print a single branch of the bracket.
+	let (a, _) = t.child_nodes(*t.grand_finals())?;
+	let out = write(
+		&t,
+		&PrintConfig {
+			root: Some(a),
+			..PrintConfig::default()
+		},
+	)?;
+	assert!(out.lines().count() < t.graph().node_count());
+	assert!(matches!(
+		write(
+			&t,
+			&PrintConfig {
+				root: Some(NodeIndex::new(1000)),
+				..PrintConfig::default()
+			},
+		),
+		Err(TournamentError::RoundNotFound(_))
+	));
+	assert!(matches!(
+		crate::write_tournament(&t, &mut BrokenWriter, &PrintConfig::default()),
+		Err(TournamentError::Io(e)) if e.kind() == std::io::ErrorKind::BrokenPipe
+	));
+	Ok(())
+}
+
+fn places(
+	t: &Tournament<IntFighter, String, IntBattleSystem>,
+) -> Result<Vec<(usize, usize)>> {
+	Ok(
+		t.standings()?
+			.iter()
+			.map(|s| (s.place, s.lowest_place))
+			.collect(),
+	)
+}
+
+#[test]
+fn standings() -> Result<()> {
+	let mut t = seeded_int_tournament(8, Seeding::Standard)?;
+	// Nobody has lost yet, so everyone shares every place.
+	assert_eq!(places(&t)?, vec![(1, 8); 8]);
+	let (semi_a, semi_b) = t.child_nodes(*t.grand_finals())?;
+	t.solve_round(semi_a)?;
+	t.solve_round(semi_b)?;
+	assert_eq!(&places(&t)?[..4], &[(1, 2), (1, 2), (3, 4), (3, 4)]);
+	t.solve()?;
+	let standings = t.standings()?;
+	assert_eq!(standings[0].eliminated_in, None);
+	assert_eq!(standings[1].eliminated_in, Some(*t.grand_finals()));
+	assert_eq!(standings[0].to_string(), "Entrant #0: 1st");
+	assert_eq!(standings[1].to_string(), "Entrant #1: 2nd");
+	assert_eq!(standings[2].to_string(), "Entrant #2: 3rd-4th");
+	assert_eq!(standings[7].to_string(), "Entrant #7: 5th-8th");
+
+	// Byes leave some layers short of a full bracket, so the ties are only as wide as the number of entrants who lost there.
+	let expected: Vec<(usize, Vec<usize>)> = vec![
+		(1, vec![1]),
+		(2, vec![1, 2]),
+		(3, vec![1, 2, 3]),
+		(5, vec![1, 2, 3, 3, 5]),
+		(6, vec![1, 2, 3, 3, 5, 5]),
+		(7, vec![1, 2, 3, 3, 5, 5, 5]),
+		(12, vec![1, 2, 3, 3, 5, 5, 5, 5, 9, 9, 9, 9]),
+		(17, vec![1, 2, 3, 3, 5, 5, 5, 5, 9, 9, 9, 9, 9, 9, 9, 9, 17]),
+	];
+	for (size, expected) in expected {
+		let mut t = seeded_int_tournament(size, Seeding::Standard)?;
+		t.solve()?;
+		let standings = t.standings()?;
+		// The strongest seeds win every round they play, so entrants finish in seed order.
+		for (i, standing) in standings.iter().enumerate() {
+			assert_eq!(standing.entrant, EntrantId(i));
+			assert_eq!(standing.place, expected[i]);
+			let tied = expected.iter().filter(|p| **p == expected[i]).count();
+			assert_eq!(standing.lowest_place, expected[i] + tied - 1);
+			if let Some(round) = standing.eliminated_in {
+				assert_eq!(t.loser(round)?, Some(standing.entrant));
+			}
+		}
+	}
+
+	// A solved third place playoff splits the semifinal losers.
+	let mut t =
+		seeded_int_tournament(8, Seeding::Standard)?.with_third_place_playoff()?;
+	t.solve()?;
+	let standings = t.standings()?;
+	assert_eq!(&places(&t)?[..4], &[(1, 1), (2, 2), (3, 3), (4, 4)]);
+	assert_eq!(standings[3].to_string(), "Entrant #3: 4th");
+	assert_eq!(standings[3].eliminated_in, t.third_place().copied());
+	Ok(())
+}
+
+/// The number of rounds between round `id` and the grand finals, found by walking up the bracket.
+fn rounds_to_final(
+	t: &Tournament<IntFighter, String, IntBattleSystem>,
+	id: NodeIndex,
+) -> usize {
+	let mut node = id;
+	let mut rounds = 0;
+	while node != *t.grand_finals() {
+		node = t
+			.graph()
+			.neighbors_directed(node, Direction::Incoming)
+			.find(|parent| t.graph()[*parent].round().is_some())
+			.unwrap();
+		rounds += 1;
+	}
+	rounds
+}
+
+type Standing = (usize, usize, usize);
+
+#[test]
+fn standings_with_byes() -> Result<()> {
+	// `Tournament::new` gives byes at every level of the bracket, not just the first round. Entrant `i` is `IntFighter(i + 1)`, so the higher id always wins.
+	// Each size, with the entrant, place and lowest place of every standing, and the playoff's winner and loser.
+	let cases: Vec<(usize, Vec<Standing>, Vec<usize>)> = vec![
+		// A bye straight into the grand finals is still second place.
+		(3, vec![(2, 1, 1), (0, 2, 2), (1, 3, 3)], vec![]),
+		(
+			5,
+			vec![(4, 1, 1), (1, 2, 2), (0, 3, 4), (2, 3, 4), (3, 5, 5)],
+			vec![2, 0],
+		),
+		(
+			6,
+			vec![
+				(5, 1, 1),
+				(2, 2, 2),
+				(0, 3, 4),
+				(3, 3, 4),
+				(1, 5, 6),
+				(4, 5, 6),
+			],
+			vec![3, 0],
+		),
+		(
+			7,
+			vec![
+				(6, 1, 1),
+				(2, 2, 2),
+				(0, 3, 4),
+				(4, 3, 4),
+				(1, 5, 7),
+				(3, 5, 7),
+				(5, 5, 7),
+			],
+			vec![4, 0],
+		),
+		(
+			12,
+			vec![
+				(11, 1, 1),
+				(5, 2, 2),
+				(2, 3, 4),
+				(8, 3, 4),
+				(0, 5, 8),
+				(3, 5, 8),
+				(6, 5, 8),
+				(9, 5, 8),
+				(1, 9, 12),
+				(4, 9, 12),
+				(7, 9, 12),
+				(10, 9, 12),
+			],
+			vec![8, 2],
+		),
+	];
+	for (size, expected, third_place) in cases {
+		let new = || {
+			Tournament::<IntFighter, String, IntBattleSystem>::new(
+				(1..=size as u32).map(IntFighter).collect(),
+			)
+		};
+		let mut t = new()?;
+		t.solve()?;
+		let standings = t.standings()?;
+		let found: Vec<Standing> = standings
+			.iter()
+			.map(|s| (s.entrant.0, s.place, s.lowest_place))
+			.collect();
+		assert_eq!(found, expected);
+		// Entrants share a place exactly when they lost equally far from the grand finals, however many rounds they played to get there.
+		for a in standings.iter() {
+			for b in standings.iter() {
+				let lost = |s: &BracketStanding| {
+					s.eliminated_in.map(|round| rounds_to_final(&t, round))
+				};
+				assert_eq!(lost(a) == lost(b), a.place == b.place);
+			}
+		}
+
+		// The playoff splits the semifinal losers, and leaves everybody else alone.
+		let playoff = new()?.with_third_place_playoff();
+		if third_place.is_empty() {
+			assert!(matches!(
+				playoff,
+				Err(TournamentError::NeedsAtLeastFourEntrants)
+			));
+			continue;
+		}
+		let mut t = playoff?;
+		t.solve()?;
+		let mut expected = expected;
+		expected[2] = (third_place[0], 3, 3);
+		expected[3] = (third_place[1], 4, 4);
+		let found: Vec<Standing> = t
+			.standings()?
+			.iter()
+			.map(|s| (s.entrant.0, s.place, s.lowest_place))
+			.collect();
+		assert_eq!(found, expected);
+	}
+	Ok(())
+}
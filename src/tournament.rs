@@ -1,20 +1,57 @@
+use crate::observer::*;
+use crate::seeding::*;
+#[cfg(feature = "serde")]
+use crate::serialization::*;
+use crate::series::*;
 use crate::types::*;
 #[doc(hidden)]
 use petgraph::prelude::*;
 #[doc(no_inline)]
 use petgraph::{graph::NodeIndex, Graph};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{RngCore, SeedableRng};
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use std::borrow::Cow;
 use std::clone::Clone;
 use std::default::Default;
 use std::fmt;
 use std::fmt::{Debug, Display};
 use std::sync::{Arc, RwLock};
+#[cfg(feature = "parallel")]
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+/// States of entrants cloned before the rounds they played, in the order the rounds were played.
+pub(crate) type Snapshots<E> = Vec<(NodeIndex, Vec<(EntrantId, E)>)>;
+
+/// The result of a round between entrants `a` and `b` that's decided by walkover, or `None` if neither has withdrawn. If both have, `A` advances, and loses its next round by walkover in turn.
+pub(crate) fn walkover(
+	withdrawn: &[EntrantId],
+	a: EntrantId,
+	b: EntrantId,
+) -> Option<TournamentRoundResult> {
+	match (withdrawn.contains(&a), withdrawn.contains(&b)) {
+		(false, false) => None,
+		(true, false) => Some(TournamentRoundResult::B),
+		(_, true) => Some(TournamentRoundResult::A),
+	}
+}
+
+/// A node of the bracket and where it's drawn, as laid out by `Tournament::place_slot()`.
+pub(crate) struct Slot {
+	pub id: NodeIndex,
+	pub parent: Option<NodeIndex>,
+	pub depth: usize,
+	pub row: f64,
+}
 
 /// `Tournament<E, M, B>` is the core structure of the package. Creates a single-elimination tournament bracket.
 /// - **`E`** - The entrant structs that will battle each other. Must implement `Debug`, `Display` and `Clone`.
 /// 	- Internally, these are cloned, then stored as [`Arc`](https://doc.rust-lang.org/std/sync/struct.Arc.html)`<`[`RwLock`](https://doc.rust-lang.org/std/sync/struct.RwLock.html)`<E>>`, and are accessed through them after the tournament is created.
 /// - **`M`** - The metadata struct that is added to rounds after being completed. Must implement `Debug`, `Display`, `Clone` and `Default`
 /// - **`B`** - The battle system that solves rounds between two entrants of type `E`. Must implement [`BattleSystem<E, M>`](trait.BattleSystem.html)
+/// 	- The tournament owns an instance of it, which is either passed to [`new_with_battle_system()`](#method.new_with_battle_system), or created with `B::default()` by the other constructors.
 #[derive(Debug)]
 pub struct Tournament<
 	E: Debug + Display + Clone,
@@ -24,14 +61,20 @@ pub struct Tournament<
 	graph: Graph<TournamentNode<M>, TournamentEdge>,
 	entrants: Vec<Arc<RwLock<E>>>,
 	grand_finals: NodeIndex,
-	phantom: std::marker::PhantomData<B>,
+	third_place: Option<NodeIndex>,
+	battle_system: B,
+	rng: StdRng,
+	snapshots: Option<Snapshots<E>>,
+	observers: Observers<M>,
+	withdrawn: Vec<EntrantId>,
+	best_of: SeriesLengths,
 	phantom_metadata: std::marker::PhantomData<M>,
 }
 
 impl<
 		E: fmt::Debug + fmt::Display + Clone,
 		M: Debug + Display + Clone + Default,
-		B: BattleSystem<E, M>,
+		B: BattleSystem<E, M> + Default,
 	> Tournament<E, M, B>
 {
 	/// Create a new `Tournament` from a `Vec<E>` of entrant structs. Brackets are assigned in the `Vec<E>`'s order.
@@ -39,112 +82,355 @@ impl<
 	/// # Example
 	/// Create a `Tournament` that battles a vec of `u32`s
 	/// ```
-	/// use crate::{ MyBattleSystem, MyMetadata };
-	///
+	/// # use ultra_tournament::*;
+	/// # use rand::RngCore;
+	/// # use std::sync::{Arc, RwLock};
+	/// # #[derive(Debug, Clone, Default)]
+	/// # struct MyMetadata;
+	/// # impl MyMetadata {
+	/// # 	fn new() -> Self {
+	/// # 		Self
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Display for MyMetadata {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "My Metadata")
+	/// # 	}
+	/// # }
+	/// # #[derive(Clone, Default)]
+	/// # struct MyBattleSystem;
+	/// # impl BattleSystem<u32, MyMetadata> for MyBattleSystem {
+	/// # 	fn battle(&mut self, a: Arc<RwLock<u32>>, b: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> BattleResult<MyMetadata> {
+	/// # 		if *a.read().unwrap() >= *b.read().unwrap() {
+	/// # 			BattleResult::Solved(TournamentRoundResult::A, MyMetadata::new())
+	/// # 		} else {
+	/// # 			BattleResult::Solved(TournamentRoundResult::B, MyMetadata::new())
+	/// # 		}
+	/// # 	}
+	/// # 	fn tiebreaker(&mut self, _: Arc<RwLock<u32>>, _: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> TiebreakerResult<MyMetadata> {
+	/// # 		Ok((TournamentRoundResult::A, MyMetadata::new()))
+	/// # 	}
+	/// # }
+	/// # fn main() {
 	/// let entrants = vec![1, 2, 3, 23, 35, 483, 9494, 9, 0, 102, 48];
 	///
 	/// let t = Tournament::<u32, MyMetadata, MyBattleSystem>::new(entrants);
+	/// # }
 	/// ```
 	pub fn new(entrants: Vec<E>) -> Result<Self> {
-		if entrants.len() == 0 {
+		Self::new_seeded(entrants, Seeding::Ordered)
+	}
+
+	/// Create a new `Tournament` from a `Vec<E>` of entrant structs, placed into the bracket with a [`Seeding`](enum.Seeding.html) strategy. [`EntrantId`](struct.EntrantId.html)s always match the entrants' positions in the `Vec<E>`, regardless of where they're placed.
+	///
+	/// # Example
+	/// Create a `Tournament` where the top two seeds can only meet in the final
+	/// ```
+	/// # use ultra_tournament::*;
+	/// # use rand::RngCore;
+	/// # use std::sync::{Arc, RwLock};
+	/// # #[derive(Debug, Clone, Default)]
+	/// # struct MyMetadata;
+	/// # impl MyMetadata {
+	/// # 	fn new() -> Self {
+	/// # 		Self
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Display for MyMetadata {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "My Metadata")
+	/// # 	}
+	/// # }
+	/// # #[derive(Clone, Default)]
+	/// # struct MyBattleSystem;
+	/// # impl BattleSystem<u32, MyMetadata> for MyBattleSystem {
+	/// # 	fn battle(&mut self, a: Arc<RwLock<u32>>, b: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> BattleResult<MyMetadata> {
+	/// # 		if *a.read().unwrap() >= *b.read().unwrap() {
+	/// # 			BattleResult::Solved(TournamentRoundResult::A, MyMetadata::new())
+	/// # 		} else {
+	/// # 			BattleResult::Solved(TournamentRoundResult::B, MyMetadata::new())
+	/// # 		}
+	/// # 	}
+	/// # 	fn tiebreaker(&mut self, _: Arc<RwLock<u32>>, _: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> TiebreakerResult<MyMetadata> {
+	/// # 		Ok((TournamentRoundResult::A, MyMetadata::new()))
+	/// # 	}
+	/// # }
+	/// # fn main() {
+	/// let seeds = vec![9494, 483, 102, 48, 35, 23, 9, 3, 2, 1, 0];
+	///
+	/// let t = Tournament::<u32, MyMetadata, MyBattleSystem>::new_seeded(
+	/// 	seeds,
+	/// 	Seeding::Standard,
+	/// );
+	/// # }
+	/// ```
+	pub fn new_seeded(entrants: Vec<E>, seeding: Seeding) -> Result<Self> {
+		Self::new_with_battle_system(entrants, seeding, B::default())
+	}
+
+	/// Created a new `Tournament` of a specified number of entrants, using a generation closure that returns a new entrant.
+	///
+	/// # Example
+	/// Create a `Tournament` that battles 200 randomly generated `u32`s, reproducible from a seed
+	/// ```
+	/// use rand::prelude::*;
+	/// # use ultra_tournament::*;
+	/// # use rand::RngCore;
+	/// # use std::sync::{Arc, RwLock};
+	/// # #[derive(Debug, Clone, Default)]
+	/// # struct MyMetadata;
+	/// # impl MyMetadata {
+	/// # 	fn new() -> Self {
+	/// # 		Self
+	/// # 	}
+	/// # }
+	/// # impl std::fmt::Display for MyMetadata {
+	/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+	/// # 		write!(f, "My Metadata")
+	/// # 	}
+	/// # }
+	/// # #[derive(Clone, Default)]
+	/// # struct MyBattleSystem;
+	/// # impl BattleSystem<u32, MyMetadata> for MyBattleSystem {
+	/// # 	fn battle(&mut self, a: Arc<RwLock<u32>>, b: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> BattleResult<MyMetadata> {
+	/// # 		if *a.read().unwrap() >= *b.read().unwrap() {
+	/// # 			BattleResult::Solved(TournamentRoundResult::A, MyMetadata::new())
+	/// # 		} else {
+	/// # 			BattleResult::Solved(TournamentRoundResult::B, MyMetadata::new())
+	/// # 		}
+	/// # 	}
+	/// # 	fn tiebreaker(&mut self, _: Arc<RwLock<u32>>, _: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> TiebreakerResult<MyMetadata> {
+	/// # 		Ok((TournamentRoundResult::A, MyMetadata::new()))
+	/// # 	}
+	/// # }
+	/// # fn main() {
+	/// let mut rng = StdRng::seed_from_u64(1234);
+	///
+	/// let t = Tournament::<u32, MyMetadata, MyBattleSystem>::new_from_gen(
+	/// 	200,
+	/// 	|| rng.gen::<u32>()
+	/// );
+	/// # }
+	/// ```
+	pub fn new_from_gen(size: usize, mut gen: impl FnMut() -> E) -> Result<Self> {
+		let mut entrants: Vec<E> = Vec::new();
+		for _ in 0..size {
+			entrants.push((gen)());
+		}
+		Self::new(entrants)
+	}
+}
+
+impl<
+		E: fmt::Debug + fmt::Display + Clone,
+		M: Debug + Display + Clone + Default,
+		B: BattleSystem<E, M>,
+	> Tournament<E, M, B>
+{
+	/// Create a new `Tournament` from a `Vec<E>` of entrant structs, placed into the bracket with a [`Seeding`](enum.Seeding.html) strategy, as per [`new_seeded()`](#method.new_seeded). Rounds are solved with the supplied instance of the battle system, which can carry configuration and state between rounds.
+	pub fn new_with_battle_system(
+		entrants: Vec<E>,
+		seeding: Seeding,
+		battle_system: B,
+	) -> Result<Self> {
+		if entrants.is_empty() {
 			return Err(TournamentError::NeedsAtLeastOneEntrant);
 		}
 
 		let entrant_arcs: Vec<Arc<RwLock<E>>> = entrants
 			.into_iter()
-			.map(|entrant| Arc::new(RwLock::new(entrant.clone())))
+			.map(|entrant| Arc::new(RwLock::new(entrant)))
 			.collect();
 
-		let mut graph: Graph<TournamentNode<M>, TournamentEdge> = Graph::new();
 		let mut entrant_ids: Vec<EntrantId> = vec![];
 		for i in 0..entrant_arcs.len() {
 			entrant_ids.push(EntrantId(i));
 		}
 
-		let grand_finals = if entrant_arcs.len() == 1 {
-			graph.add_node(TournamentNode::Entrant(EntrantId(0)))
-		} else {
-			graph.add_node(TournamentNode::Round(TournamentRound::<M>::Incomplete))
+		let (graph, grand_finals) = match seeding {
+			Seeding::Ordered => Self::add_ordered(entrant_ids),
+			mut seeding => {
+				let seeds = match &mut seeding {
+					Seeding::Random(rng) => {
+						entrant_ids.shuffle(*rng);
+						entrant_ids
+					}
+					Seeding::Custom(order) => {
+						let mut sorted = order.clone();
+						sorted.sort();
+						if sorted != entrant_ids {
+							return Err(TournamentError::InvalidSeeding);
+						}
+						order.clone()
+					}
+					_ => entrant_ids,
+				};
+				let mut graph: Graph<TournamentNode<M>, TournamentEdge> = Graph::new();
+				let slots = seeded_slots(&seeding, &seeds);
+				let grand_finals = Self::add_slots(&mut graph, &slots)
+					.ok_or(TournamentError::MalformedBracket)?;
+				(graph, grand_finals)
+			}
 		};
 
-		graph = Self::add_layer(graph, grand_finals, entrant_ids);
-
 		Ok(Tournament::<E, M, B> {
 			graph,
 			entrants: entrant_arcs,
 			grand_finals,
-			phantom: std::marker::PhantomData,
+			third_place: None,
+			battle_system,
+			rng: StdRng::from_entropy(),
+			snapshots: None,
+			observers: Observers::new(),
+			withdrawn: vec![],
+			best_of: SeriesLengths::default(),
 			phantom_metadata: std::marker::PhantomData,
 		})
 	}
 
-	fn add_layer(
-		old_graph: Graph<TournamentNode<M>, TournamentEdge>,
+	fn add_ordered(
+		entrant_ids: Vec<EntrantId>,
+	) -> (Graph<TournamentNode<M>, TournamentEdge>, NodeIndex) {
+		// A bracket of `n` entrants has `n - 1` rounds, and an edge into every node but the grand finals.
+		let mut graph: Graph<TournamentNode<M>, TournamentEdge> =
+			Graph::with_capacity(entrant_ids.len() * 2, entrant_ids.len() * 2);
+		let grand_finals = if entrant_ids.len() == 1 {
+			graph.add_node(TournamentNode::Entrant(entrant_ids[0]))
+		} else {
+			graph.add_node(TournamentNode::Round(TournamentRound::<M>::Incomplete))
+		};
+
+		Self::add_layer(&mut graph, grand_finals, &entrant_ids);
+		(graph, grand_finals)
+	}
+
+	/// Adds the rounds and entrants below `parent`, splitting `entrants` in half at every level.
+	pub(crate) fn add_layer(
+		graph: &mut Graph<TournamentNode<M>, TournamentEdge>,
 		parent: NodeIndex,
-		entrants: Vec<EntrantId>,
-	) -> Graph<TournamentNode<M>, TournamentEdge> {
-		let mut graph = old_graph.clone();
-		// println!("add_layer - P: {:?} (entrants: {})", parent, entrants.len());
-
-		let incomplete = TournamentNode::<M>::Round(TournamentRound::Incomplete);
-
-		// add bye + recursion on other 2
-		if entrants.len() == 3 {
-			let p = graph.add_node(incomplete.clone());
-			let bye =
-				graph.add_node(TournamentNode::Entrant(*entrants.get(0).unwrap()));
-			graph.add_edge(parent, p, TournamentEdge::A);
-			graph.add_edge(parent, bye, TournamentEdge::B);
-			graph =
-				Self::add_layer(graph, p, entrants.split_first().unwrap().1.to_vec());
-		}
-		// add regular
-		else if entrants.len() == 2 {
-			let a =
-				graph.add_node(TournamentNode::Entrant(*entrants.get(0).unwrap()));
-			let b =
-				graph.add_node(TournamentNode::Entrant(*entrants.get(1).unwrap()));
-			graph.add_edge(parent, a, TournamentEdge::A);
-			graph.add_edge(parent, b, TournamentEdge::B);
-		}
-		// add nothing
-		else if entrants.len() == 1 {
-		}
-		// do recursion
-		else {
-			let (slice_a, slice_b) = entrants.split_at(entrants.len() / 2);
-			let vec_a = slice_a.to_vec();
-			let vec_b = slice_b.to_vec();
-			let p_a = graph.add_node(incomplete.clone());
-			let p_b = graph.add_node(incomplete.clone());
-			graph.add_edge(parent, p_a, TournamentEdge::A);
-			graph.add_edge(parent, p_b, TournamentEdge::B);
-			graph = Self::add_layer(graph, p_a, vec_a);
-			graph = Self::add_layer(graph, p_b, vec_b);
-		}
-
-		graph
+		entrants: &[EntrantId],
+	) {
+		let incomplete = || TournamentNode::<M>::Round(TournamentRound::Incomplete);
+
+		match entrants {
+			// add nothing
+			[] | [_] => {}
+			// add regular
+			[a, b] => {
+				let a = graph.add_node(TournamentNode::Entrant(*a));
+				let b = graph.add_node(TournamentNode::Entrant(*b));
+				graph.add_edge(parent, a, TournamentEdge::A);
+				graph.add_edge(parent, b, TournamentEdge::B);
+			}
+			// add bye + recursion on other 2
+			[bye, rest @ ..] if rest.len() == 2 => {
+				let p = graph.add_node(incomplete());
+				let bye = graph.add_node(TournamentNode::Entrant(*bye));
+				graph.add_edge(parent, p, TournamentEdge::A);
+				graph.add_edge(parent, bye, TournamentEdge::B);
+				Self::add_layer(graph, p, rest);
+			}
+			// do recursion
+			_ => {
+				let (slice_a, slice_b) = entrants.split_at(entrants.len() / 2);
+				let p_a = graph.add_node(incomplete());
+				let p_b = graph.add_node(incomplete());
+				graph.add_edge(parent, p_a, TournamentEdge::A);
+				graph.add_edge(parent, p_b, TournamentEdge::B);
+				Self::add_layer(graph, p_a, slice_a);
+				Self::add_layer(graph, p_b, slice_b);
+			}
+		}
 	}
 
-	/// Created a new `Tournament` of a specified number of entrants, using a generation closure that returns a new entrant.
+	fn add_slots(
+		graph: &mut Graph<TournamentNode<M>, TournamentEdge>,
+		slots: &[Option<EntrantId>],
+	) -> Option<NodeIndex> {
+		if slots.len() <= 1 {
+			return slots
+				.first()
+				.cloned()
+				.flatten()
+				.map(|eid| graph.add_node(TournamentNode::Entrant(eid)));
+		}
+		let (slots_a, slots_b) = slots.split_at(slots.len() / 2);
+		match (
+			Self::add_slots(graph, slots_a),
+			Self::add_slots(graph, slots_b),
+		) {
+			(Some(a), Some(b)) => {
+				let p = graph
+					.add_node(TournamentNode::Round(TournamentRound::<M>::Incomplete));
+				graph.add_edge(p, a, TournamentEdge::A);
+				graph.add_edge(p, b, TournamentEdge::B);
+				Some(p)
+			}
+			// A slot facing a bye moves straight on to the next round.
+			(a, b) => a.or(b),
+		}
+	}
+
+	/// Add a third place playoff to the tournament, played between the losers of the two semifinals, and return the tournament. The playoff is a round of its own, linked to each semifinal by a [`TournamentNode::Loser`](enum.TournamentNode.html#variant.Loser) node, and is solved by [`solve()`](#method.solve) just before the grand finals. Adding it twice does nothing.
+	///
+	/// For [`set_best_of_depth()`](#method.set_best_of_depth), the playoff is at depth `1`, the same as the semifinals, so a longer grand finals doesn't make it longer too.
+	///
+	/// Returns [`TournamentError::NeedsAtLeastFourEntrants`](enum.TournamentError.html#variant.NeedsAtLeastFourEntrants) if both sides of the grand finals aren't semifinal rounds, which takes at least four entrants.
 	///
 	/// # Example
-	/// Create a `Tournament` that battles 200 randomly generated `u32`s
 	/// ```
-	/// use rand::prelude::*;
-	/// use crate::{ MyBattleSystem, MyMetadata };
+	/// # use ultra_tournament::*;
+	/// # use rand::RngCore;
+	/// # use std::sync::{Arc, RwLock};
+	/// # #[derive(Clone, Default)]
+	/// # struct MyBattleSystem;
+	/// # impl BattleSystem<u32, String> for MyBattleSystem {
+	/// # 	fn battle(&mut self, a: Arc<RwLock<u32>>, b: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> BattleResult<String> {
+	/// # 		if *a.read().unwrap() >= *b.read().unwrap() {
+	/// # 			BattleResult::Solved(TournamentRoundResult::A, String::new())
+	/// # 		} else {
+	/// # 			BattleResult::Solved(TournamentRoundResult::B, String::new())
+	/// # 		}
+	/// # 	}
+	/// # 	fn tiebreaker(&mut self, _: Arc<RwLock<u32>>, _: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> TiebreakerResult<String> {
+	/// # 		Ok((TournamentRoundResult::A, String::new()))
+	/// # 	}
+	/// # }
+	/// # fn main() -> Result<()> {
+	/// let mut t = Tournament::<u32, String, MyBattleSystem>::new(vec![1, 2, 3, 4, 5])?
+	/// 	.with_third_place_playoff()?;
+	/// t.solve()?;
 	///
-	/// let t = Tournament::<u32, MyMetadata, MyBattleSystem>::new_from_gen(
-	/// 	200,
-	/// 	|| random::<u32>()
-	/// );
+	/// let third_place = t.third_place().copied().unwrap();
+	/// assert_eq!(t.winner(third_place)?, Some(EntrantId(2)));
+	/// # Ok(())
+	/// # }
 	/// ```
-	pub fn new_from_gen(size: usize, gen: fn() -> E) -> Result<Self> {
-		let mut entrants: Vec<E> = Vec::new();
-		for _ in 0..size {
-			entrants.push((gen)());
+	pub fn with_third_place_playoff(mut self) -> Result<Self> {
+		if self.third_place.is_some() {
+			return Ok(self);
 		}
-		Self::new(entrants)
+		let semis = match self.graph.node_weight(self.grand_finals) {
+			Some(TournamentNode::Round(_)) => self.child_nodes(self.grand_finals)?,
+			_ => return Err(TournamentError::NeedsAtLeastFourEntrants),
+		};
+		if self.graph[semis.0].round().is_none()
+			|| self.graph[semis.1].round().is_none()
+		{
+			return Err(TournamentError::NeedsAtLeastFourEntrants);
+		}
+		let playoff = self
+			.graph
+			.add_node(TournamentNode::Round(TournamentRound::Incomplete));
+		for (semi, side) in
+			[(semis.0, TournamentEdge::A), (semis.1, TournamentEdge::B)].iter()
+		{
+			let loser = self.graph.add_node(TournamentNode::Loser);
+			self.graph.add_edge(loser, *semi, TournamentEdge::A);
+			self.graph.add_edge(playoff, loser, *side);
+		}
+		self.third_place = Some(playoff);
+		Ok(self)
 	}
 
 	/// Get the number of entrants in the tournament.
@@ -157,7 +443,7 @@ impl<
 		let mut c = 0;
 		for node in self.graph().node_indices() {
 			match self.graph()[node] {
-				TournamentNode::Entrant(_) => {}
+				TournamentNode::Entrant(_) | TournamentNode::Loser => {}
 				TournamentNode::Round(_) => c += 1,
 			};
 		}
@@ -168,10 +454,8 @@ impl<
 	pub fn len_rounds_complete(&self) -> usize {
 		let mut c = 0;
 		for node in self.graph().node_indices() {
-			if let TournamentNode::Round(TournamentRound::Complete {
-				result: _,
-				metadata: _,
-			}) = self.graph()[node]
+			if let TournamentNode::Round(TournamentRound::Complete { .. }) =
+				self.graph()[node]
 			{
 				c += 1;
 			}
@@ -202,13 +486,93 @@ impl<
 		&self.grand_finals
 	}
 
+	/// Get a ref to the [`NodeIndex`](https://docs.rs/petgraph/0.5.1/petgraph/graph/struct.NodeIndex.html) of the third place playoff, if the tournament was created [with one](#method.with_third_place_playoff).
+	pub fn third_place(&self) -> Option<&NodeIndex> {
+		self.third_place.as_ref()
+	}
+
 	/// Get a ref to the internal [`Graph`](https://docs.rs/petgraph/0.5.1/petgraph/graph/struct.Graph.html) used by the tournament. `ultra_tournament` is built using the [`petgraph`](https://docs.rs/petgraph/0.5.1/petgraph/index.html) crate.
 	pub fn graph(&self) -> &Graph<TournamentNode<M>, TournamentEdge> {
 		&self.graph
 	}
 
+	/// Get a ref to the battle system used to solve rounds.
+	pub fn battle_system(&self) -> &B {
+		&self.battle_system
+	}
+
+	/// Get a mutable ref to the battle system used to solve rounds, to change its configuration between rounds.
+	pub fn battle_system_mut(&mut self) -> &mut B {
+		&mut self.battle_system
+	}
+
+	/// Seed the random number generator passed to the battle system, so that solving the tournament is reproducible. By default, it's seeded from system entropy.
+	pub fn set_seed(&mut self, seed: u64) {
+		self.rng = StdRng::seed_from_u64(seed);
+	}
+
+	/// Replace the random number generator passed to the battle system. Pass in the generator used for [`Seeding::Random`](enum.Seeding.html#variant.Random) to continue its sequence, so that a single seed reproduces both the bracket and its results.
+	pub fn set_rng(&mut self, rng: StdRng) {
+		self.rng = rng;
+	}
+
+	/// Enable or disable entrant snapshots. While enabled, the state of both entrants is cloned before every round the battle system solves, so that [`revert_round()`](#method.revert_round) can restore them. Disabled by default. Disabling discards every snapshot taken so far.
+	pub fn set_snapshots(&mut self, enabled: bool) {
+		self.snapshots = if enabled {
+			self.snapshots.take().or_else(|| Some(vec![]))
+		} else {
+			None
+		};
+	}
+
+	/// Whether entrant snapshots are enabled, as per [`set_snapshots()`](#method.set_snapshots).
+	pub fn snapshots_enabled(&self) -> bool {
+		self.snapshots.is_some()
+	}
+
+	/// Register a [`TournamentObserver`](trait.TournamentObserver.html) to receive events while the tournament is solved. Observers are sent events in the order they were added.
+	pub fn add_observer(
+		&mut self,
+		observer: impl TournamentObserver<M> + Send + 'static,
+	) {
+		self.observers.add(observer);
+	}
+
+	/// Remove every registered [`TournamentObserver`](trait.TournamentObserver.html).
+	pub fn clear_observers(&mut self) {
+		self.observers.clear();
+	}
+
+	/// A copy of the tournament in its current state, with clones of every entrant and the same withdrawn entrants and series lengths, that solves rounds with `battle_system` and `rng`. Observers and snapshots aren't copied.
+	pub(crate) fn fork(&self, battle_system: B, rng: StdRng) -> Result<Self> {
+		let entrants = self
+			.entrants
+			.iter()
+			.enumerate()
+			.map(|(i, arc)| {
+				let entrant = arc
+					.read()
+					.or(Err(TournamentError::EntrantLockPoisoned(EntrantId(i))))?;
+				Ok(Arc::new(RwLock::new(entrant.clone())))
+			})
+			.collect::<Result<Vec<Arc<RwLock<E>>>>>()?;
+		Ok(Tournament {
+			graph: self.graph.clone(),
+			entrants,
+			grand_finals: self.grand_finals,
+			third_place: self.third_place,
+			battle_system,
+			rng,
+			snapshots: None,
+			observers: Observers::new(),
+			withdrawn: self.withdrawn.clone(),
+			best_of: self.best_of.clone(),
+			phantom_metadata: std::marker::PhantomData,
+		})
+	}
+
 	// ====================================
-	fn _child_node(
+	pub(crate) fn _child_node(
 		graph: &Graph<TournamentNode<M>, TournamentEdge>,
 		id: NodeIndex,
 		target: TournamentEdge,
@@ -216,8 +580,8 @@ impl<
 		use TournamentError::*;
 		let mut children = graph.edges_directed(id, petgraph::Direction::Outgoing);
 		let child_edges = (
-			children.next().ok_or(MalformedBracket)?,
-			children.next().ok_or(MalformedBracket)?,
+			children.next().ok_or(MalformedRound(id))?,
+			children.next().ok_or(MalformedRound(id))?,
 		);
 
 		// TODO Why do these have to be backwards? But why? But why?
@@ -226,7 +590,7 @@ impl<
 		} else if child_edges.1.weight() == &target {
 			Ok(child_edges.0.target())
 		} else {
-			Err(MalformedBracket)
+			Err(MalformedRound(id))
 		}
 	}
 
@@ -238,7 +602,7 @@ impl<
 	) -> Result<NodeIndex> {
 		Self::_child_node(&self.graph, id, target)
 	}
-	fn _child_nodes(
+	pub(crate) fn _child_nodes(
 		graph: &Graph<TournamentNode<M>, TournamentEdge>,
 		id: NodeIndex,
 	) -> Result<(NodeIndex, NodeIndex)> {
@@ -253,7 +617,7 @@ impl<
 		Self::_child_nodes(&self.graph, id)
 	}
 
-	fn _winner(
+	pub(crate) fn _winner(
 		graph: &Graph<TournamentNode<M>, TournamentEdge>,
 		id: NodeIndex,
 	) -> Result<Option<EntrantId>> {
@@ -265,28 +629,330 @@ impl<
 			Entrant(entrant_id) => Some(*entrant_id),
 			Round(round) => match round {
 				TournamentRound::Incomplete => None,
-				TournamentRound::<M>::Complete {
-					result,
-					metadata: _,
-				} => match result {
-					&TournamentRoundResult::A => Self::_winner(
+				TournamentRound::<M>::Complete { result, .. } => match *result {
+					TournamentRoundResult::A => Self::_winner(
 						graph,
 						Self::_child_node(graph, id, TournamentEdge::A)?,
 					)?,
-					&TournamentRoundResult::B => Self::_winner(
+					TournamentRoundResult::B => Self::_winner(
 						graph,
 						Self::_child_node(graph, id, TournamentEdge::B)?,
 					)?,
 				},
 			},
+			Loser => Self::_loser(
+				graph,
+				graph
+					.neighbors_directed(id, petgraph::Direction::Outgoing)
+					.next()
+					.ok_or(MalformedRound(id))?,
+			)?,
+		})
+	}
+
+	pub(crate) fn _loser(
+		graph: &Graph<TournamentNode<M>, TournamentEdge>,
+		id: NodeIndex,
+	) -> Result<Option<EntrantId>> {
+		use TournamentError::*;
+
+		let cur_res = graph.node_weight(id).ok_or(RoundNotFound(id))?;
+		Ok(match cur_res.result() {
+			Some(TournamentRoundResult::A) => {
+				Self::_winner(graph, Self::_child_node(graph, id, TournamentEdge::B)?)?
+			}
+			Some(TournamentRoundResult::B) => {
+				Self::_winner(graph, Self::_child_node(graph, id, TournamentEdge::A)?)?
+			}
+			None => None,
+		})
+	}
+
+	pub(crate) fn _report_result(
+		graph: &mut Graph<TournamentNode<M>, TournamentEdge>,
+		id: NodeIndex,
+		result: TournamentRoundResult,
+		metadata: M,
+	) -> Result<EntrantId> {
+		use TournamentError::*;
+
+		match graph.node_weight(id).ok_or(RoundNotFound(id))? {
+			TournamentNode::Round(TournamentRound::Incomplete) => {}
+			TournamentNode::Round(_) => return Err(RoundAlreadyComplete(id)),
+			_ => return Err(NotARound(id)),
+		}
+		let (a, b) = Self::_child_nodes(graph, id)?;
+		let (ent_a, ent_b) =
+			match (Self::_winner(graph, a)?, Self::_winner(graph, b)?) {
+				(Some(ent_a), Some(ent_b)) => (ent_a, ent_b),
+				_ => return Err(RoundNotReady(id)),
+			};
+		graph[id] = TournamentNode::Round(TournamentRound::Complete {
+			result,
+			metadata,
+			decision: RoundDecision::Played,
+			series: vec![],
+		});
+		Ok(match result {
+			TournamentRoundResult::A => ent_a,
+			TournamentRoundResult::B => ent_b,
 		})
 	}
 
+	/// Records the result of a round that was played outside of the battle system, such as by humans, and returns its winner. Reported rounds can be freely mixed with rounds solved by the battle system.
+	///
+	/// Observers are sent [`on_round_complete()`](trait.TournamentObserver.html#method.on_round_complete), but not [`on_round_start()`](trait.TournamentObserver.html#method.on_round_start). The round must be ready to play, as per [`ready_rounds()`](#method.ready_rounds). Returns [`TournamentError::RoundNotReady`](enum.TournamentError.html#variant.RoundNotReady) if either side hasn't been decided yet, [`TournamentError::RoundAlreadyComplete`](enum.TournamentError.html#variant.RoundAlreadyComplete) if the round already has a result, and [`TournamentError::NotARound`](enum.TournamentError.html#variant.NotARound) if the node isn't a round.
+	pub fn report_result(
+		&mut self,
+		id: NodeIndex,
+		result: TournamentRoundResult,
+		metadata: M,
+	) -> Result<EntrantId> {
+		let winner = Self::_report_result(&mut self.graph, id, result, metadata)?;
+		if let Some(metadata) = self.graph[id].metadata() {
+			self.observers.round_complete(id, result, metadata);
+		}
+		if id == self.grand_finals {
+			self.observers.tournament_complete(winner);
+		}
+		Ok(winner)
+	}
+
+	/// Resets round `id` and every complete round above it, returning the rounds that were reset.
+	pub(crate) fn _revert_round(
+		graph: &mut Graph<TournamentNode<M>, TournamentEdge>,
+		id: NodeIndex,
+	) -> Result<Vec<NodeIndex>> {
+		use TournamentError::*;
+
+		match graph.node_weight(id).ok_or(RoundNotFound(id))? {
+			TournamentNode::Round(TournamentRound::Complete { .. }) => {}
+			TournamentNode::Round(TournamentRound::Incomplete) => return Ok(vec![]),
+			_ => return Err(NotARound(id)),
+		}
+		let mut reverted = vec![];
+		let mut stack = vec![id];
+		while let Some(node) = stack.pop() {
+			if let TournamentNode::Round(round) = &mut graph[node] {
+				if round.result().is_none() {
+					continue;
+				}
+				*round = TournamentRound::Incomplete;
+				reverted.push(node);
+			}
+			// Walk up through rounds, and through the loser nodes that feed a losers bracket.
+			stack
+				.extend(graph.neighbors_directed(node, petgraph::Direction::Incoming));
+		}
+		Ok(reverted)
+	}
+
+	/// Clones the state of the entrants about to play round `id`, if snapshots are enabled.
+	pub(crate) fn _snapshot(
+		snapshots: &mut Option<Snapshots<E>>,
+		entrants: &[Arc<RwLock<E>>],
+		id: NodeIndex,
+		ids: &[EntrantId],
+	) -> Result<()> {
+		use TournamentError::*;
+
+		if let Some(snapshots) = snapshots {
+			let mut states = vec![];
+			for eid in ids {
+				let arc = entrants.get(eid.0).ok_or(EntrantNotFound(*eid))?;
+				let state = arc.read().or(Err(EntrantLockPoisoned(*eid)))?;
+				states.push((*eid, state.clone()));
+			}
+			snapshots.push((id, states));
+		}
+		Ok(())
+	}
+
+	/// Restores the entrants to their state before the earliest of the `reverted` rounds they played, discarding those rounds' snapshots.
+	pub(crate) fn _restore(
+		snapshots: &mut Option<Snapshots<E>>,
+		entrants: &[Arc<RwLock<E>>],
+		reverted: &[NodeIndex],
+	) -> Result<()> {
+		use TournamentError::*;
+
+		if let Some(snapshots) = snapshots {
+			// Latest first, so that each entrant ends up in its earliest state.
+			for (id, states) in snapshots.iter().rev() {
+				if !reverted.contains(id) {
+					continue;
+				}
+				for (eid, state) in states {
+					let arc = entrants.get(eid.0).ok_or(EntrantNotFound(*eid))?;
+					*arc.write().or(Err(EntrantLockPoisoned(*eid)))? = state.clone();
+				}
+			}
+			snapshots.retain(|(id, _)| !reverted.contains(id));
+		}
+		Ok(())
+	}
+
+	/// Resets a completed round back to [`TournamentRound::Incomplete`](enum.TournamentRound.html#variant.Incomplete), along with every later round that depended on its result, walking up towards the [grand finals](#method.grand_finals). Returns every round that was reset, or nothing if the round was already incomplete.
+	///
+	/// If [snapshots](#method.set_snapshots) are enabled, entrants are also restored to their state before the earliest reset round they played. Returns [`TournamentError::NotARound`](enum.TournamentError.html#variant.NotARound) if the node isn't a round.
+	pub fn revert_round(&mut self, id: NodeIndex) -> Result<Vec<NodeIndex>> {
+		let reverted = Self::_revert_round(&mut self.graph, id)?;
+		Self::_restore(&mut self.snapshots, &self.entrants, &reverted)?;
+		Ok(reverted)
+	}
+
+	/// Withdraw an entrant from the tournament, such as when they drop out or are disqualified. From then on, every round they reach is decided by [walkover](enum.RoundDecision.html#variant.Walkover) for their opponent as it's solved, without calling the battle system, and gets `M::default()` as its metadata. Withdrawing an entrant twice does nothing.
+	///
+	/// Rounds the entrant has already played keep their results. To overturn them, as for a disqualification, [revert](#method.revert_round) the rounds and solve them again. If both entrants of a round have withdrawn, side `A` advances. Returns [`TournamentError::EntrantNotFound`](enum.TournamentError.html#variant.EntrantNotFound) if the entrant isn't in the tournament.
+	pub fn withdraw(&mut self, id: EntrantId) -> Result<()> {
+		if id.0 >= self.entrants.len() {
+			return Err(TournamentError::EntrantNotFound(id));
+		}
+		if !self.withdrawn.contains(&id) {
+			self.withdrawn.push(id);
+		}
+		Ok(())
+	}
+
+	/// Whether an entrant has [withdrawn](#method.withdraw).
+	pub fn is_withdrawn(&self, id: EntrantId) -> bool {
+		self.withdrawn.contains(&id)
+	}
+
+	/// Get every entrant that has [withdrawn](#method.withdraw), in the order they withdrew.
+	pub fn withdrawn(&self) -> &Vec<EntrantId> {
+		&self.withdrawn
+	}
+
+	/// Play every round of the tournament as a best-of-`games` series, where the battle system is called repeatedly until one side has won a majority of the games. Every game is kept in the round's [`series`](enum.TournamentRound.html#variant.Complete.field.series), and the round takes the metadata of the deciding game. Rounds are a single game by default.
+	///
+	/// Lengths set for a depth with [`set_best_of_depth()`](#method.set_best_of_depth) or for a round with [`set_best_of_round()`](#method.set_best_of_round) take priority. Only affects rounds solved afterwards. Returns [`TournamentError::InvalidBestOf`](enum.TournamentError.html#variant.InvalidBestOf) if `games` isn't odd.
+	///
+	/// # Example
+	/// Play best-of-3 series, with best-of-5 semifinals and a best-of-7 grand finals
+	/// ```
+	/// # use ultra_tournament::*;
+	/// # use rand::RngCore;
+	/// # use std::sync::{Arc, RwLock};
+	/// # #[derive(Clone, Default)]
+	/// # struct MyBattleSystem;
+	/// # impl BattleSystem<u32, String> for MyBattleSystem {
+	/// # 	fn battle(&mut self, a: Arc<RwLock<u32>>, b: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> BattleResult<String> {
+	/// # 		if *a.read().unwrap() >= *b.read().unwrap() {
+	/// # 			BattleResult::Solved(TournamentRoundResult::A, String::new())
+	/// # 		} else {
+	/// # 			BattleResult::Solved(TournamentRoundResult::B, String::new())
+	/// # 		}
+	/// # 	}
+	/// # 	fn tiebreaker(&mut self, _: Arc<RwLock<u32>>, _: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> TiebreakerResult<String> {
+	/// # 		Ok((TournamentRoundResult::A, String::new()))
+	/// # 	}
+	/// # }
+	/// # fn main() -> Result<()> {
+	/// let mut t = Tournament::<u32, String, MyBattleSystem>::new((1..=8).collect())?;
+	/// t.set_best_of(3)?;
+	/// t.set_best_of_depth(1, 5)?;
+	/// t.set_best_of_round(*t.grand_finals(), 7)?;
+	/// t.solve()?;
+	///
+	/// // The stronger finalist sweeps the series in four games.
+	/// assert_eq!(t.graph()[*t.grand_finals()].series().map(Vec::len), Some(4));
+	/// # Ok(())
+	/// # }
+	/// ```
+	pub fn set_best_of(&mut self, games: u32) -> Result<()> {
+		self.best_of.default = SeriesLengths::check(games)?;
+		Ok(())
+	}
+
+	/// Play every round `depth` rounds below the grand finals as a best-of-`games` series, as per [`set_best_of()`](#method.set_best_of). The grand finals are at depth `0`, the semifinals at depth `1`, and so on. The [third place playoff](#method.with_third_place_playoff) is at depth `1`, alongside the semifinals its entrants lost.
+	pub fn set_best_of_depth(&mut self, depth: usize, games: u32) -> Result<()> {
+		let games = SeriesLengths::check(games)?;
+		SeriesLengths::set(&mut self.best_of.depths, depth, games);
+		Ok(())
+	}
+
+	/// Play round `id` as a best-of-`games` series, as per [`set_best_of()`](#method.set_best_of). Returns [`TournamentError::NotARound`](enum.TournamentError.html#variant.NotARound) if the node isn't a round.
+	pub fn set_best_of_round(&mut self, id: NodeIndex, games: u32) -> Result<()> {
+		match self.graph.node_weight(id) {
+			Some(TournamentNode::Round(_)) => {}
+			Some(_) => return Err(TournamentError::NotARound(id)),
+			None => return Err(TournamentError::RoundNotFound(id)),
+		}
+		let games = SeriesLengths::check(games)?;
+		SeriesLengths::set(&mut self.best_of.rounds, id, games);
+		Ok(())
+	}
+
+	/// Get the number of games round `id` is played over, as per [`set_best_of()`](#method.set_best_of). Returns [`TournamentError::NotARound`](enum.TournamentError.html#variant.NotARound) if the node isn't a round.
+	pub fn best_of(&self, id: NodeIndex) -> Result<u32> {
+		match self.graph.node_weight(id) {
+			Some(TournamentNode::Round(_)) => {
+				Ok(self.best_of.get(id, Self::depth(&self.graph, id)))
+			}
+			Some(_) => Err(TournamentError::NotARound(id)),
+			None => Err(TournamentError::RoundNotFound(id)),
+		}
+	}
+
+	/// The number of rounds between round `id` and the grand finals. The third place playoff is at the same depth as the semifinals.
+	fn depth(
+		graph: &Graph<TournamentNode<M>, TournamentEdge>,
+		id: NodeIndex,
+	) -> usize {
+		let mut depth = 0;
+		let mut node = id;
+		while let Some(parent) = graph
+			.neighbors_directed(node, Direction::Incoming)
+			.find(|parent| graph[*parent].round().is_some())
+		{
+			depth += 1;
+			node = parent;
+		}
+		// A bracket fed by losers takes the depth of the rounds they lost.
+		let lost_in = graph
+			.neighbors_directed(node, Direction::Outgoing)
+			.find(|child| matches!(graph[*child], TournamentNode::Loser))
+			.and_then(|loser| {
+				graph.neighbors_directed(loser, Direction::Outgoing).next()
+			});
+		match lost_in {
+			Some(round) => depth + Self::depth(graph, round),
+			None => depth,
+		}
+	}
+
+	/// Awards round `id` to side `result` by walkover, without calling the battle system.
+	pub(crate) fn _walkover(
+		graph: &mut Graph<TournamentNode<M>, TournamentEdge>,
+		observers: &mut Observers<M>,
+		id: NodeIndex,
+		result: TournamentRoundResult,
+	) -> Result<()> {
+		let metadata = M::default();
+		observers.round_complete(id, result, &metadata);
+		let weight = graph
+			.node_weight_mut(id)
+			.ok_or(TournamentError::RoundNotFound(id))?;
+		*weight = TournamentNode::Round(TournamentRound::Complete {
+			result,
+			metadata,
+			decision: RoundDecision::Walkover,
+			series: vec![],
+		});
+		Ok(())
+	}
+
 	/// Get the [`EntrantId`](struct.EntrantId.html) of the solved winner of a particular round. Returns `None` if the round hasn't been calculated yet, or if the node is a [`TournamentNode::Entrant`](enum.TournamentNode.html#variant.Entrant) instead of a [`TournamentNode::Round`](enum.TournamentNode.html#variant.Round).
 	pub fn winner(&self, id: NodeIndex) -> Result<Option<EntrantId>> {
 		Self::_winner(&self.graph, id)
 	}
 
+	/// Get the [`EntrantId`](struct.EntrantId.html) of the solved loser of a particular round. Returns `None` if the round hasn't been calculated yet, or if the node isn't a [`TournamentNode::Round`](enum.TournamentNode.html#variant.Round).
+	pub fn loser(&self, id: NodeIndex) -> Result<Option<EntrantId>> {
+		Self::_loser(&self.graph, id)
+	}
+
 	/// Identical to the [`winner()`](#method.winner) function, but returns the [`Arc`](https://doc.rust-lang.org/std/sync/struct.Arc.html)`<`[`RwLock`](https://doc.rust-lang.org/std/sync/struct.RwLock.html)`<E>>` encapsulating the entrant instead of its [`EntrantId`](struct.EntrantId.html).
 	pub fn winner_entrant(
 		&self,
@@ -295,116 +961,644 @@ impl<
 		Ok(self.winner(id)?.map(|eid| self.entrant(eid)))
 	}
 
-	/// Solves all rounds in the tournament, as per [`solve_round()`](#method.solve_round), up to and including the returned by [`grand_finals()`](#method.grand_finals)
+	/// Solves all rounds in the tournament, as per [`solve_round()`](#method.solve_round), up to and including the returned by [`grand_finals()`](#method.grand_finals). The [third place playoff](#method.with_third_place_playoff), if there is one, is solved just before the grand finals.
 	pub fn solve(&mut self) -> Result<()> {
-		self.solve_round(self.grand_finals)?;
+		if let Some(third_place) = self.third_place {
+			self.solve_round(third_place)?;
+		}
+		// A tournament of one entrant has no rounds to solve.
+		if let TournamentNode::Round(_) = self.graph[self.grand_finals] {
+			self.solve_round(self.grand_finals)?;
+		}
 		Ok(())
 	}
 
-	/// Solves rounds only up to the specified round.
+	/// Get every incomplete round that can be played right now, because both of its children already have a winner. Rounds are ordered by depth, deepest first, with the third place playoff just before the grand finals.
+	pub fn ready_rounds(&self) -> Result<Vec<NodeIndex>> {
+		let mut ready = vec![];
+		for layer in self.solving_layers()?.iter().rev() {
+			for id in layer {
+				if self.is_ready(*id)? {
+					ready.push(*id);
+				}
+			}
+		}
+		Ok(ready)
+	}
+
+	/// Solves only the first round returned by [`ready_rounds()`](#method.ready_rounds), and returns it. Returns `None` if every round has already been solved.
+	pub fn solve_next(&mut self) -> Result<Option<NodeIndex>> {
+		match self.ready_rounds()?.first() {
+			Some(id) => {
+				self.solve_round(*id)?;
+				Ok(Some(*id))
+			}
+			None => Ok(None),
+		}
+	}
+
+	/// Solves every incomplete round at the deepest layer of the bracket that still has incomplete rounds, and returns them. Calling this repeatedly plays out the tournament one layer at a time. Returns an empty `Vec` if every round has already been solved.
+	pub fn solve_layer(&mut self) -> Result<Vec<NodeIndex>> {
+		let layers = self.solving_layers()?;
+		let mut solved = vec![];
+		for layer in layers.iter().rev() {
+			for id in layer {
+				if self.graph[*id].result().is_none() {
+					self.solve_round(*id)?;
+					solved.push(*id);
+				}
+			}
+			if !solved.is_empty() {
+				break;
+			}
+		}
+		Ok(solved)
+	}
+
+	/// Lays out node `id` and everything below it, returning its row. Entrants take up one row each, in bracket order, and rounds sit halfway between their children.
+	pub(crate) fn place_slot(
+		&self,
+		id: NodeIndex,
+		parent: Option<NodeIndex>,
+		depth: usize,
+		rows: &mut usize,
+		slots: &mut Vec<Slot>,
+	) -> Result<f64> {
+		let row = match self.graph()[id] {
+			TournamentNode::Round(_) => {
+				let (a, b) = self.child_nodes(id)?;
+				let row_a = self.place_slot(a, Some(id), depth + 1, rows, slots)?;
+				let row_b = self.place_slot(b, Some(id), depth + 1, rows, slots)?;
+				(row_a + row_b) / 2.0
+			}
+			_ => {
+				*rows += 1;
+				(*rows - 1) as f64
+			}
+		};
+		slots.push(Slot {
+			id,
+			parent,
+			depth,
+			row,
+		});
+		Ok(row)
+	}
+
+	/// Every round of the bracket, as per `layers()`, with the third place playoff first in the grand finals' layer.
+	fn solving_layers(&self) -> Result<Vec<Vec<NodeIndex>>> {
+		let mut layers = self.layers()?;
+		if let (Some(third_place), Some(finals)) =
+			(self.third_place, layers.first_mut())
+		{
+			finals.insert(0, third_place);
+		}
+		Ok(layers)
+	}
+
+	/// Every round of the bracket, grouped by distance from the grand finals. The third place playoff isn't part of the bracket.
+	pub(crate) fn layers(&self) -> Result<Vec<Vec<NodeIndex>>> {
+		let is_round = |id: &NodeIndex| self.graph[*id].round().is_some();
+		let mut layers = vec![];
+		let mut layer: Vec<NodeIndex> = Some(self.grand_finals)
+			.into_iter()
+			.filter(is_round)
+			.collect();
+		while !layer.is_empty() {
+			let mut next = vec![];
+			for id in layer.iter() {
+				let (a, b) = self.child_nodes(*id)?;
+				next.extend([a, b].iter().filter(|id| is_round(id)));
+			}
+			layers.push(layer);
+			layer = next;
+		}
+		Ok(layers)
+	}
+
+	fn is_ready(&self, id: NodeIndex) -> Result<bool> {
+		if self.graph[id].result().is_some() {
+			return Ok(false);
+		}
+		let (a, b) = self.child_nodes(id)?;
+		Ok(self.winner(a)?.is_some() && self.winner(b)?.is_some())
+	}
+
+	/// Solves rounds only up to the specified round. Rounds that have already been solved aren't replayed. Returns [`TournamentError::NotARound`](enum.TournamentError.html#variant.NotARound) if the node isn't a round.
+	///
+	/// If the battle system [fails](enum.BattleResult.html#variant.Failed) partway through, solving stops and [`TournamentError::BattleFailed`](enum.TournamentError.html#variant.BattleFailed) is returned. The tournament is left consistent:
+	/// - Rounds solved before the failure keep their results.
+	/// - The failed round, and every round above it, stay [`TournamentRound::Incomplete`](enum.TournamentRound.html#variant.Incomplete), so solving again picks up where it left off.
+	/// - If [snapshots](#method.set_snapshots) are enabled, the entrants of the failed round are restored to their state before it.
+	///
+	/// Rounds reached by a [withdrawn](#method.withdraw) entrant are decided by walkover instead of being played. Observers are sent [`on_round_complete()`](trait.TournamentObserver.html#method.on_round_complete) for them, but not [`on_round_start()`](trait.TournamentObserver.html#method.on_round_start).
+	///
+	/// Rounds played as a [series](#method.set_best_of) are sent `on_round_start()` and `on_round_complete()` once each, and [`on_tie()`](trait.TournamentObserver.html#method.on_tie) for every tied game. If any game fails, the whole series is left incomplete.
 	pub fn solve_round(
 		&mut self,
 		id: NodeIndex,
 	) -> Result<TournamentRoundResult> {
-		let mut graph = self.graph.clone();
-		let res = self.solve_rec(&self.entrants.clone(), &mut graph, id)?;
-		self.graph = graph;
-		Ok(res)
+		match self.graph.node_weight(id) {
+			Some(TournamentNode::Round(TournamentRound::Complete {
+				result, ..
+			})) => return Ok(*result),
+			Some(TournamentNode::Round(TournamentRound::Incomplete)) => {}
+			Some(_) => return Err(TournamentError::NotARound(id)),
+			None => return Err(TournamentError::RoundNotFound(id)),
+		}
+		let depth = Self::depth(&self.graph, id);
+		Self::solve_rec(
+			&self.entrants,
+			&mut self.battle_system,
+			&mut self.rng,
+			&mut self.snapshots,
+			&mut self.observers,
+			&self.withdrawn,
+			&self.best_of,
+			&mut self.graph,
+			id,
+			depth,
+		)?;
+		// Only the grand finals decide the champion, so solving the third place playoff afterwards isn't a second completion.
+		if id == self.grand_finals {
+			if let Some(winner) = self.winner(id)? {
+				self.observers.tournament_complete(winner);
+			}
+		}
+		self.graph[id]
+			.result()
+			.copied()
+			.ok_or(TournamentError::MalformedRound(id))
 	}
+
+	/// Solves round `id` and every incomplete round below it in place, returning its winner.
+	#[allow(clippy::too_many_arguments)]
 	fn solve_rec(
-		&self,
-		entrants: &Vec<Arc<RwLock<E>>>,
-		old_graph: &mut Graph<TournamentNode<M>, TournamentEdge>,
+		entrants: &[Arc<RwLock<E>>],
+		battle_system: &mut B,
+		rng: &mut dyn RngCore,
+		snapshots: &mut Option<Snapshots<E>>,
+		observers: &mut Observers<M>,
+		withdrawn: &[EntrantId],
+		best_of: &SeriesLengths,
+		graph: &mut Graph<TournamentNode<M>, TournamentEdge>,
 		id: NodeIndex,
-	) -> Result<TournamentRoundResult> {
+		depth: usize,
+	) -> Result<EntrantId> {
 		use TournamentError::*;
 		use TournamentNode::*;
-		let mut graph = old_graph.clone();
-		let mut children =
-			graph.neighbors_directed(id, petgraph::Direction::Outgoing);
-		let a = children.next().ok_or(Other("Child A not found"))?;
-		let b = children.next().ok_or(Other("Child B not found"))?;
-
-		macro_rules! do_bye {
-			($ent_bye:expr, $other_node:expr, $bye_is:expr) => {{
-				let ent_round = Self::_winner(&graph, $other_node)?.unwrap_or({
-					self.solve_rec(entrants, &mut graph, $other_node)?;
-					Self::_winner(&graph, $other_node)?
-						.ok_or(Other("Solving Bye failed"))?
-				});
-				let arc_bye = entrants
-					.get($ent_bye.0)
-					.ok_or(EntrantNotFound($ent_bye))?
-					.clone();
-				let arc_round = entrants
-					.get(ent_round.0)
-					.ok_or(EntrantNotFound(ent_round))?
-					.clone();
-				match $bye_is {
-					TournamentEdge::A => (
-						arc_bye.clone(),
-						arc_round.clone(),
-						B::battle(arc_bye.clone(), arc_round.clone()),
-					),
-					TournamentEdge::B => (
-						arc_round.clone(),
-						arc_bye.clone(),
-						B::battle(arc_round.clone(), arc_bye.clone()),
-					),
+		let (a, b) = Self::_child_nodes(graph, id)?;
+
+		macro_rules! solve_child {
+			($node:expr) => {
+				match graph.node_weight($node).ok_or(RoundNotFound($node))? {
+					Entrant(eid) => *eid,
+					Round(TournamentRound::Incomplete) => Self::solve_rec(
+						entrants,
+						battle_system,
+						rng,
+						snapshots,
+						observers,
+						withdrawn,
+						best_of,
+						graph,
+						$node,
+						depth + 1,
+					)?,
+					Round(TournamentRound::Complete { .. }) => {
+						Self::_winner(graph, $node)?.ok_or(MalformedRound($node))?
+					}
+					// The loser of another round, such as a semifinal feeding the third place playoff.
+					Loser => {
+						let round = graph
+							.neighbors_directed($node, Direction::Outgoing)
+							.next()
+							.ok_or(MalformedRound($node))?;
+						if let Round(TournamentRound::Incomplete) = graph[round] {
+							Self::solve_rec(
+								entrants,
+								battle_system,
+								rng,
+								snapshots,
+								observers,
+								withdrawn,
+								best_of,
+								graph,
+								round,
+								Self::depth(graph, round),
+							)?;
+						}
+						Self::_winner(graph, $node)?.ok_or(MalformedRound($node))?
+					}
 				}
-				}};
+			};
 		}
 
-		let (arc_a, arc_b, res) = match (
-			graph.node_weight(a).unwrap().clone(),
-			graph.node_weight(b).unwrap().clone(),
-		) {
-			(Entrant(id_a), Entrant(id_b)) => {
-				let arc_a = entrants.get(id_a.0).ok_or(EntrantNotFound(id_a))?.clone();
-				let arc_b = entrants.get(id_b.0).ok_or(EntrantNotFound(id_b))?.clone();
-				(
-					arc_a.clone(),
-					arc_b.clone(),
-					B::battle(arc_a.clone(), arc_b.clone()),
-				)
-			}
-			(Entrant(ent_bye), Round(_)) => do_bye!(ent_bye, b, TournamentEdge::A),
-			(Round(_), Entrant(ent_bye)) => do_bye!(ent_bye, a, TournamentEdge::B),
-			(Round(_), Round(_)) => {
-				let ent_a = Self::_winner(&graph, a)?.unwrap_or({
-					self.solve_rec(entrants, &mut graph, a)?;
-					Self::_winner(&graph, a)?
-						.ok_or(Other("Finding winner failed for A"))?
-				});
-				let ent_b = Self::_winner(&graph, b)?.unwrap_or({
-					self.solve_rec(entrants, &mut graph, b)?;
-					Self::_winner(&graph, b)?
-						.ok_or(Other("Finding winner failed for B"))?
-				});
-				let arc_a =
-					entrants.get(ent_a.0).ok_or(EntrantNotFound(ent_a))?.clone();
-				let arc_b =
-					entrants.get(ent_b.0).ok_or(EntrantNotFound(ent_b))?.clone();
-				(
-					arc_a.clone(),
-					arc_b.clone(),
-					B::battle(arc_a.clone(), arc_b.clone()),
-				)
-			}
-		};
+		let ent_a = solve_child!(a);
+		let ent_b = solve_child!(b);
+		if let Some(result) = walkover(withdrawn, ent_a, ent_b) {
+			Self::_walkover(graph, observers, id, result)?;
+			return Ok(match result {
+				TournamentRoundResult::A => ent_a,
+				TournamentRoundResult::B => ent_b,
+			});
+		}
+		let arc_a = entrants.get(ent_a.0).ok_or(EntrantNotFound(ent_a))?.clone();
+		let arc_b = entrants.get(ent_b.0).ok_or(EntrantNotFound(ent_b))?.clone();
 
-		let (result, metadata) = match res {
-			BattleResult::Solved(round_result, metadata) => (round_result, metadata),
-			BattleResult::Tie => B::tiebreaker(arc_a, arc_b),
+		observers.round_start(id, ent_a, ent_b);
+		Self::_snapshot(snapshots, entrants, id, &[ent_a, ent_b])?;
+		let outcome = play_series(
+			battle_system,
+			&arc_a,
+			&arc_b,
+			rng,
+			best_of.get(id, depth),
+			|| observers.tie(id),
+		);
+		let (result, metadata, series) = match outcome {
+			Ok(outcome) => outcome,
+			Err(e) => {
+				// The round stays incomplete, and its entrants go back to how they were before it.
+				Self::_restore(snapshots, entrants, &[id])?;
+				return Err(BattleFailed(id, e));
+			}
 		};
+		observers.round_complete(id, result, &metadata);
 		let weight = graph.node_weight_mut(id).ok_or(RoundNotFound(id))?;
 		*weight = TournamentNode::Round(TournamentRound::<M>::Complete {
 			result,
 			metadata,
+			decision: RoundDecision::Played,
+			series,
 		});
 
-		*old_graph = graph;
-		Ok(result)
+		Ok(match result {
+			TournamentRoundResult::A => ent_a,
+			TournamentRoundResult::B => ent_b,
+		})
+	}
+}
+
+/// Locks `mutex`. Panics in the battle system are passed on by rayon, so a poisoned lock's contents are still sound.
+#[cfg(feature = "parallel")]
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+	mutex.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// State shared between the threads of [`Tournament::solve_parallel()`](struct.Tournament.html#method.solve_parallel). The graph is only read while solving, and completed rounds are collected to be written back afterwards.
+#[cfg(feature = "parallel")]
+struct ParallelSolve<'a, E, M: Debug + Display + Clone + Default> {
+	graph: &'a Graph<TournamentNode<M>, TournamentEdge>,
+	entrants: &'a [Arc<RwLock<E>>],
+	withdrawn: &'a [EntrantId],
+	best_of: &'a SeriesLengths,
+	seed: u64,
+	snapshots: Mutex<&'a mut Option<Snapshots<E>>>,
+	observers: Mutex<&'a mut Observers<M>>,
+	completed: Mutex<Vec<(NodeIndex, TournamentRound<M>)>>,
+}
+
+#[cfg(feature = "parallel")]
+impl<
+		E: fmt::Debug + fmt::Display + Clone + Send + Sync,
+		M: Debug + Display + Clone + Default + Send + Sync,
+		B: BattleSystem<E, M> + Clone + Send,
+	> Tournament<E, M, B>
+{
+	/// Solves all rounds in the tournament, as per [`solve()`](#method.solve), but solves the `A` and `B` sides of every round at the same time, on [rayon](https://docs.rs/rayon)'s thread pool. Requires the **`parallel`** feature.
+	///
+	/// - Each side is solved with its own clone of the battle system, so state that the battle system carries between rounds isn't shared between sides, and isn't kept by the tournament afterwards.
+	/// - Each round gets its own random number generator, seeded from the tournament's. Battle systems that don't use randomness give the same results as [`solve()`](#method.solve). Ones that do are still reproducible with [`set_seed()`](#method.set_seed), but give different results from `solve()`.
+	/// - Observers are sent the events of different rounds in whichever order the rounds are played.
+	pub fn solve_parallel(&mut self) -> Result<()> {
+		if let Some(third_place) = self.third_place {
+			self.solve_round_parallel(third_place)?;
+		}
+		if let TournamentNode::Round(_) = self.graph[self.grand_finals] {
+			self.solve_round_parallel(self.grand_finals)?;
+		}
+		Ok(())
+	}
+
+	/// Solves rounds only up to the specified round, as per [`solve_round()`](#method.solve_round), in parallel as per [`solve_parallel()`](#method.solve_parallel). Requires the **`parallel`** feature.
+	pub fn solve_round_parallel(
+		&mut self,
+		id: NodeIndex,
+	) -> Result<TournamentRoundResult> {
+		match self.graph.node_weight(id) {
+			Some(TournamentNode::Round(TournamentRound::Complete {
+				result, ..
+			})) => return Ok(*result),
+			Some(TournamentNode::Round(TournamentRound::Incomplete)) => {}
+			Some(_) => return Err(TournamentError::NotARound(id)),
+			None => return Err(TournamentError::RoundNotFound(id)),
+		}
+		// Rounds that a loser comes from, such as the semifinals before the third place playoff, are solved first.
+		let (a, b) = self.child_nodes(id)?;
+		for child in [a, b].iter() {
+			if let TournamentNode::Loser = self.graph[*child] {
+				let round = self
+					.graph
+					.neighbors_directed(*child, Direction::Outgoing)
+					.next()
+					.ok_or(TournamentError::MalformedRound(*child))?;
+				self.solve_round_parallel(round)?;
+			}
+		}
+		let shared = ParallelSolve {
+			graph: &self.graph,
+			entrants: &self.entrants,
+			withdrawn: &self.withdrawn,
+			best_of: &self.best_of,
+			seed: self.rng.next_u64(),
+			snapshots: Mutex::new(&mut self.snapshots),
+			observers: Mutex::new(&mut self.observers),
+			completed: Mutex::new(vec![]),
+		};
+		let res = Self::solve_rec_parallel(
+			&shared,
+			self.battle_system.clone(),
+			id,
+			Self::depth(&self.graph, id),
+		);
+		let completed = shared
+			.completed
+			.into_inner()
+			.unwrap_or_else(PoisonError::into_inner);
+		// Rounds completed before a failure are kept.
+		for (node, round) in completed {
+			self.graph[node] = TournamentNode::Round(round);
+		}
+		res?;
+		if id == self.grand_finals {
+			if let Some(winner) = self.winner(id)? {
+				self.observers.tournament_complete(winner);
+			}
+		}
+		self.graph[id]
+			.result()
+			.copied()
+			.ok_or(TournamentError::MalformedRound(id))
+	}
+
+	/// Solves round `id` and every incomplete round below it, returning its winner.
+	fn solve_rec_parallel(
+		shared: &ParallelSolve<E, M>,
+		mut battle_system: B,
+		id: NodeIndex,
+		depth: usize,
+	) -> Result<EntrantId> {
+		use TournamentError::*;
+
+		let (a, b) = Self::_child_nodes(shared.graph, id)?;
+		let solve_child =
+			|node: NodeIndex, battle_system: B| -> Result<EntrantId> {
+				match (Self::_winner(shared.graph, node)?, &shared.graph[node]) {
+					(Some(eid), _) => Ok(eid),
+					(None, TournamentNode::Round(_)) => {
+						Self::solve_rec_parallel(shared, battle_system, node, depth + 1)
+					}
+					_ => Err(MalformedRound(node)),
+				}
+			};
+		let (bs_a, bs_b) = (battle_system.clone(), battle_system.clone());
+		let (ent_a, ent_b) =
+			rayon::join(|| solve_child(a, bs_a), || solve_child(b, bs_b));
+		let (ent_a, ent_b) = (ent_a?, ent_b?);
+		let winner = |result| match result {
+			TournamentRoundResult::A => ent_a,
+			TournamentRoundResult::B => ent_b,
+		};
+		if let Some(result) = walkover(shared.withdrawn, ent_a, ent_b) {
+			let metadata = M::default();
+			lock(&shared.observers).round_complete(id, result, &metadata);
+			lock(&shared.completed).push((
+				id,
+				TournamentRound::Complete {
+					result,
+					metadata,
+					decision: RoundDecision::Walkover,
+					series: vec![],
+				},
+			));
+			return Ok(winner(result));
+		}
+
+		let arc_a = shared
+			.entrants
+			.get(ent_a.0)
+			.ok_or(EntrantNotFound(ent_a))?
+			.clone();
+		let arc_b = shared
+			.entrants
+			.get(ent_b.0)
+			.ok_or(EntrantNotFound(ent_b))?
+			.clone();
+		let mut rng =
+			StdRng::seed_from_u64(shared.seed.wrapping_add(id.index() as u64));
+
+		lock(&shared.observers).round_start(id, ent_a, ent_b);
+		{
+			let mut snapshots = lock(&shared.snapshots);
+			Self::_snapshot(&mut snapshots, shared.entrants, id, &[ent_a, ent_b])?;
+		}
+		let outcome = play_series(
+			&mut battle_system,
+			&arc_a,
+			&arc_b,
+			&mut rng,
+			shared.best_of.get(id, depth),
+			|| lock(&shared.observers).tie(id),
+		);
+		let (result, metadata, series) = match outcome {
+			Ok(outcome) => outcome,
+			Err(e) => {
+				let mut snapshots = lock(&shared.snapshots);
+				Self::_restore(&mut snapshots, shared.entrants, &[id])?;
+				return Err(BattleFailed(id, e));
+			}
+		};
+		lock(&shared.observers).round_complete(id, result, &metadata);
+		lock(&shared.completed).push((
+			id,
+			TournamentRound::Complete {
+				result,
+				metadata,
+				decision: RoundDecision::Played,
+				series,
+			},
+		));
+		Ok(winner(result))
+	}
+}
+
+/// The serialized form of a [`Tournament`](struct.Tournament.html), as documented on [`SCHEMA_VERSION`](constant.SCHEMA_VERSION.html).
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Tournament")]
+struct TournamentData<Es, G, W, S> {
+	version: u32,
+	entrants: Es,
+	graph: G,
+	grand_finals: NodeIndex,
+	#[serde(default)]
+	third_place: Option<NodeIndex>,
+	#[serde(default)]
+	withdrawn: W,
+	#[serde(default)]
+	best_of: S,
+}
+
+#[cfg(feature = "serde")]
+impl<
+		E: Debug + Display + Clone + Serialize,
+		M: Debug + Display + Clone + Default + Serialize,
+		B: BattleSystem<E, M>,
+	> Serialize for Tournament<E, M, B>
+{
+	fn serialize<S: Serializer>(
+		&self,
+		serializer: S,
+	) -> std::result::Result<S::Ok, S::Error> {
+		TournamentData {
+			version: SCHEMA_VERSION,
+			entrants: EntrantsRef(&self.entrants),
+			graph: &self.graph,
+			grand_finals: self.grand_finals,
+			third_place: self.third_place,
+			withdrawn: &self.withdrawn,
+			best_of: &self.best_of,
+		}
+		.serialize(serializer)
+	}
+}
+
+#[cfg(feature = "serde")]
+impl<
+		'de,
+		E: Debug + Display + Clone + Deserialize<'de>,
+		M: Debug + Display + Clone + Default + Deserialize<'de>,
+		B: BattleSystem<E, M> + Default,
+	> Deserialize<'de> for Tournament<E, M, B>
+{
+	fn deserialize<D: Deserializer<'de>>(
+		deserializer: D,
+	) -> std::result::Result<Self, D::Error> {
+		let data = TournamentData::<
+			Vec<E>,
+			Graph<TournamentNode<M>, TournamentEdge>,
+			Vec<EntrantId>,
+			SeriesLengths,
+		>::deserialize(deserializer)?;
+		check_version(data.version)?;
+		let mut roots = vec![data.grand_finals];
+		roots.extend(data.third_place);
+		check_bracket(&data.graph, &roots, &data.withdrawn, data.entrants.len())?;
+		Ok(Tournament {
+			graph: data.graph,
+			entrants: entrant_arcs(data.entrants),
+			grand_finals: data.grand_finals,
+			third_place: data.third_place,
+			battle_system: B::default(),
+			rng: StdRng::from_entropy(),
+			snapshots: None,
+			observers: Observers::new(),
+			withdrawn: data.withdrawn,
+			best_of: data.best_of,
+			phantom_metadata: std::marker::PhantomData,
+		})
+	}
+}
+
+/// The characters a bracket is drawn with: horizontal and vertical lines, the top and bottom corners, the junction in front of a round, and the ellipsis of truncated names.
+struct Glyphs {
+	horizontal: char,
+	vertical: char,
+	top: char,
+	bottom: char,
+	junction: char,
+	ellipsis: char,
+}
+impl Glyphs {
+	/// Shortens `name` to at most `len` characters, ending in an ellipsis if anything was cut.
+	fn fit(&self, name: &str, len: usize) -> String {
+		let mut chars: Vec<char> = name.chars().collect();
+		if chars.len() > len {
+			chars.truncate(len.max(1) - 1);
+			chars.push(self.ellipsis);
+		}
+		chars.into_iter().collect()
+	}
+}
+
+impl<
+		E: fmt::Debug + fmt::Display + Clone,
+		M: Debug + Display + Clone + Default,
+		B: BattleSystem<E, M>,
+	> Tournament<E, M, B>
+{
+	/// Draws the bracket laid out in `slots`, with columns `width` characters wide, as per the tournament's `Display` implementation.
+	fn draw_bracket(
+		&self,
+		slots: &[Slot],
+		names: &[String],
+		leaves: usize,
+		width: usize,
+		glyphs: &Glyphs,
+	) -> String {
+		let columns = slots.iter().map(|slot| slot.depth).max().unwrap_or(0);
+		// Byes, and the losers feeding a third place playoff, are drawn in the first column.
+		let column = |slot: &Slot| match self.graph[slot.id] {
+			TournamentNode::Round(_) => columns - slot.depth,
+			_ => 0,
+		};
+		let x = |column: usize| column * (width + 5);
+		// Leaves are two lines apart, leaving room for the connectors between them.
+		let line = |slot: &Slot| (slot.row * 2.0).floor() as usize;
+		let mut grid = vec![vec![' '; x(columns) + width]; leaves.max(1) * 2 - 1];
+
+		let by_id: std::collections::HashMap<NodeIndex, &Slot> =
+			slots.iter().map(|slot| (slot.id, slot)).collect();
+		for slot in slots.iter() {
+			let parent = match slot.parent {
+				Some(parent) => by_id[&parent],
+				None => continue,
+			};
+			let (child_line, parent_line) = (line(slot), line(parent));
+			let corner = x(column(parent)) - 3;
+			for cell in
+				grid[child_line][x(column(slot)) + width + 1..corner].iter_mut()
+			{
+				*cell = glyphs.horizontal;
+			}
+			grid[child_line][corner] = if child_line < parent_line {
+				glyphs.top
+			} else {
+				glyphs.bottom
+			};
+			let between =
+				child_line.min(parent_line) + 1..child_line.max(parent_line);
+			for row in grid[between].iter_mut() {
+				row[corner] = glyphs.vertical;
+			}
+		}
+		for (slot, name) in slots.iter().zip(names.iter()) {
+			let row = &mut grid[line(slot)];
+			let start = x(column(slot));
+			if let TournamentNode::Round(_) = self.graph[slot.id] {
+				row[start - 3] = glyphs.junction;
+				row[start - 2] = glyphs.horizontal;
+			}
+			let chars: Vec<char> = glyphs.fit(name, width).chars().collect();
+			row[start..start + chars.len()].copy_from_slice(&chars);
+		}
+
+		let lines: Vec<String> = grid
+			.iter()
+			.map(|row| row.iter().collect::<String>().trim_end().to_string())
+			.collect();
+		lines.join("\n")
 	}
 }
 
@@ -414,18 +1608,151 @@ impl<
 		B: BattleSystem<E, M>,
 	> fmt::Display for Tournament<E, M, B>
 {
+	/// Draws the bracket horizontally, with one column per round from the opening rounds on the left to the grand finals on the right. Rounds that haven't been solved yet show `TBD`. The [third place playoff](#method.with_third_place_playoff), if there is one, is drawn below the bracket, between the losers of the semifinals.
+	///
+	/// - The width, as in `{:20}`, sets the width of every column. By default, columns are as wide as the longest entrant name.
+	/// - The precision, as in `{:.12}`, truncates entrant names to at most that many characters, even when a wider width is given, as in `{:20.12}`.
+	/// - Names that don't fit in their column are truncated with an ellipsis.
+	/// - The alternate flag, as in `{:#}`, draws the connectors with plain ASCII instead of Unicode box-drawing characters.
+	///
+	/// ```text
+	/// Fighter 4 ─┐
+	///            ├─ Fighter 4 ─┐
+	/// Fighter 3 ─┘             │
+	///                          ├─ TBD
+	/// Fighter 2 ─┐             │
+	///            ├─ TBD       ─┘
+	/// Fighter 1 ─┘
+	/// ```
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-		write!(f, "TODO")
+		let glyphs = if f.alternate() {
+			Glyphs {
+				horizontal: '-',
+				vertical: '|',
+				top: '+',
+				bottom: '+',
+				junction: '+',
+				ellipsis: '~',
+			}
+		} else {
+			Glyphs {
+				horizontal: '─',
+				vertical: '│',
+				top: '┐',
+				bottom: '┘',
+				junction: '├',
+				ellipsis: '…',
+			}
+		};
+
+		let mut brackets = vec![];
+		for root in Some(self.grand_finals).into_iter().chain(self.third_place) {
+			let mut slots = vec![];
+			let mut leaves = 0;
+			self
+				.place_slot(root, None, 0, &mut leaves, &mut slots)
+				.or(Err(fmt::Error))?;
+			let mut names = vec![];
+			for slot in slots.iter() {
+				let name = match self.winner(slot.id).or(Err(fmt::Error))? {
+					Some(eid) => self.entrant_name(eid).or(Err(fmt::Error))?,
+					None => "TBD".to_string(),
+				};
+				// The precision shortens names regardless of the column width.
+				names.push(match f.precision() {
+					Some(precision) => glyphs.fit(&name, precision.max(1)),
+					None => name,
+				});
+			}
+			brackets.push((slots, names, leaves));
+		}
+		let longest = brackets
+			.iter()
+			.flat_map(|(_, names, _)| names.iter())
+			.map(|name| name.chars().count())
+			.max();
+		let width = f.width().or(longest).unwrap_or(0).max(1);
+
+		let drawn: Vec<String> = brackets
+			.iter()
+			.map(|(slots, names, leaves)| {
+				self.draw_bracket(slots, names, *leaves, width, &glyphs)
+			})
+			.collect();
+		write!(f, "{}", drawn.join("\n\n"))
+	}
+}
+
+/// Options for [`write_tournament()`](fn.write_tournament.html). Every color is `None` by default, which prints without any color.
+///
+/// # Example
+/// ```
+/// # use ultra_tournament::*;
+/// use colored::Color;
+///
+/// let config = PrintConfig {
+/// 	entrant_color: Some(Color::Green),
+/// 	metadata_color: Some(Color::BrightBlack),
+/// 	..PrintConfig::default()
+/// };
+/// ```
+#[derive(Debug, Clone)]
+pub struct PrintConfig {
+	/// The node to print the tree from. `None` prints the whole tournament, from the [grand finals](struct.Tournament.html#method.grand_finals), followed by the [third place playoff](struct.Tournament.html#method.with_third_place_playoff) if there is one.
+	pub root: Option<NodeIndex>,
+	/// Whether to print each round's result and metadata next to its winner. `true` by default.
+	pub metadata: bool,
+	/// Whether to color output with ANSI escape codes. Turn this off when writing to a file. `true` by default.
+	pub ansi: bool,
+	/// The color of entrant names.
+	pub entrant_color: Option<colored::Color>,
+	/// The color of each round's result and metadata.
+	pub metadata_color: Option<colored::Color>,
+	/// The color of rounds that haven't been solved yet.
+	pub incomplete_color: Option<colored::Color>,
+}
+impl Default for PrintConfig {
+	fn default() -> Self {
+		Self {
+			root: None,
+			metadata: true,
+			ansi: true,
+			entrant_color: None,
+			metadata_color: None,
+			incomplete_color: None,
+		}
+	}
+}
+impl PrintConfig {
+	/// Wraps `text` in the ANSI escape codes for `color`, if there is one and ANSI output is enabled.
+	fn paint(&self, text: impl Display, color: Option<colored::Color>) -> String {
+		match color {
+			Some(color) if self.ansi => {
+				format!("\x1b[{}m{}\x1b[0m", color.to_fg_str(), text)
+			}
+			_ => text.to_string(),
+		}
 	}
 }
 
-#[derive(Clone)]
 struct PrintTournament<
 	'a,
 	E: fmt::Debug + fmt::Display + Clone,
 	M: Debug + Display + Clone + Default,
 	B: BattleSystem<E, M>,
->(&'a Tournament<E, M, B>, NodeIndex);
+>(&'a Tournament<E, M, B>, NodeIndex, &'a PrintConfig);
+
+// Derived `Clone` would needlessly require `B: Clone`.
+impl<'a, E, M, B> Clone for PrintTournament<'a, E, M, B>
+where
+	E: fmt::Debug + fmt::Display + Clone,
+	M: Debug + Display + Clone + Default,
+	B: BattleSystem<E, M>,
+{
+	fn clone(&self) -> Self {
+		PrintTournament(self.0, self.1, self.2)
+	}
+}
 
 impl<'a, E, M, B> ptree::TreeItem for PrintTournament<'a, E, M, B>
 where
@@ -437,44 +1764,138 @@ where
 	fn write_self<W: std::io::Write>(
 		&self,
 		f: &mut W,
-		style: &ptree::Style,
+		_: &ptree::Style,
 	) -> std::io::Result<()> {
-		if let Some(eid) = self.0.winner(self.1).unwrap() {
-			let e_arc = self.0.entrant(eid);
-			let e_value = e_arc.read().unwrap();
-			match self.0.graph.node_weight(self.1).unwrap() {
-				TournamentNode::Entrant(_) => write!(f, "{}", style.paint(e_value)),
-				TournamentNode::Round(round) => write!(
+		let config = self.2;
+		if let Some(eid) = self.0.winner(self.1).map_err(std::io::Error::other)? {
+			let name = self.0.entrant_name(eid).map_err(std::io::Error::other)?;
+			let name = config.paint(name, config.entrant_color);
+			match &self.0.graph[self.1] {
+				TournamentNode::Round(round) if config.metadata => write!(
+					f,
+					"{} ({})",
+					name,
+					config.paint(round, config.metadata_color)
+				),
+				TournamentNode::Loser => write!(
 					f,
-					"{}",
-					format!("{} ({})", style.paint(e_value), style.paint(round))
+					"{} ({})",
+					name,
+					config.paint("Loser", config.metadata_color)
 				),
+				_ => write!(f, "{}", name),
 			}
 		} else {
-			write!(f, "{}", style.paint("Incomplete"))
+			write!(f, "{}", config.paint("Incomplete", config.incomplete_color))
 		}
 	}
-	fn children(&self) -> Cow<[Self::Child]> {
+	fn children(&self) -> Cow<'_, [Self::Child]> {
+		// A loser's round is already printed in the main tree.
+		if let TournamentNode::Loser = self.0.graph[self.1] {
+			return Cow::from(vec![]);
+		}
 		let v: Vec<_> = self
 			.0
 			.graph
 			.neighbors_directed(self.1, Direction::Outgoing)
-			.map(|i| PrintTournament(self.0, i))
+			.map(|i| PrintTournament(self.0, i, self.2))
 			.collect();
 		Cow::from(v)
 	}
 }
 
-/// Pretty-print a tournament using the crate [`ptree`](https://docs.rs/ptree/0.2.1/ptree/)
+/// Pretty-print a tournament to stdout using the crate [`ptree`](https://docs.rs/ptree/0.2.1/ptree/), as per [`write_tournament()`](fn.write_tournament.html) with the default [`PrintConfig`](struct.PrintConfig.html).
 pub fn print_tournament<
 	E: fmt::Debug + fmt::Display + Clone,
 	M: Debug + Display + Clone + Default,
-	B: BattleSystem<E, M> + Clone,
+	B: BattleSystem<E, M>,
+>(
+	t: &Tournament<E, M, B>,
+) -> Result<()> {
+	write_tournament(t, &mut std::io::stdout().lock(), &PrintConfig::default())
+}
+
+/// Pretty-print a tournament as a tree to any writer, using the crate [`ptree`](https://docs.rs/ptree/0.2.1/ptree/). Returns [`TournamentError::Io`](enum.TournamentError.html#variant.Io) if writing fails, and [`TournamentError::RoundNotFound`](enum.TournamentError.html#variant.RoundNotFound) if the [`PrintConfig`](struct.PrintConfig.html)'s root isn't in the tournament.
+///
+/// # Example
+/// Write a tournament to a file, without any color
+/// ```
+/// # use ultra_tournament::*;
+/// # use rand::RngCore;
+/// # use std::sync::{Arc, RwLock};
+/// # #[derive(Debug, Clone, Default)]
+/// # struct MyMetadata;
+/// # impl MyMetadata {
+/// # 	fn new() -> Self {
+/// # 		Self
+/// # 	}
+/// # }
+/// # impl std::fmt::Display for MyMetadata {
+/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// # 		write!(f, "My Metadata")
+/// # 	}
+/// # }
+/// # #[derive(Clone, Default)]
+/// # struct MyBattleSystem;
+/// # impl BattleSystem<u32, MyMetadata> for MyBattleSystem {
+/// # 	fn battle(&mut self, a: Arc<RwLock<u32>>, b: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> BattleResult<MyMetadata> {
+/// # 		if *a.read().unwrap() >= *b.read().unwrap() {
+/// # 			BattleResult::Solved(TournamentRoundResult::A, MyMetadata::new())
+/// # 		} else {
+/// # 			BattleResult::Solved(TournamentRoundResult::B, MyMetadata::new())
+/// # 		}
+/// # 	}
+/// # 	fn tiebreaker(&mut self, _: Arc<RwLock<u32>>, _: Arc<RwLock<u32>>, _: &mut dyn RngCore) -> TiebreakerResult<MyMetadata> {
+/// # 		Ok((TournamentRoundResult::A, MyMetadata::new()))
+/// # 	}
+/// # }
+/// # fn main() {
+/// let mut t =
+/// 	Tournament::<u32, MyMetadata, MyBattleSystem>::new(vec![1, 2, 3, 4, 5]).unwrap();
+/// t.solve().unwrap();
+///
+/// let mut file = std::fs::File::create("bracket.txt").unwrap();
+/// let config = PrintConfig {
+/// 	ansi: false,
+/// 	..PrintConfig::default()
+/// };
+/// write_tournament(&t, &mut file, &config).unwrap();
+/// # std::fs::remove_file("bracket.txt").unwrap();
+/// # }
+/// ```
+pub fn write_tournament<
+	E: fmt::Debug + fmt::Display + Clone,
+	M: Debug + Display + Clone + Default,
+	B: BattleSystem<E, M>,
+	W: std::io::Write,
 >(
 	t: &Tournament<E, M, B>,
+	writer: &mut W,
+	config: &PrintConfig,
 ) -> Result<()> {
-	#[doc(hidden)]
-	use ptree::print_tree;
-	print_tree(&PrintTournament(t, t.grand_finals))
-		.or(Err(TournamentError::PrintFailure))
+	let roots = match config.root {
+		Some(root) => vec![root],
+		None => Some(t.grand_finals)
+			.into_iter()
+			.chain(t.third_place)
+			.collect(),
+	};
+	// Colors are applied by `PrintConfig`, so ptree's own styling is turned off.
+	let tree_config = ptree::PrintConfig {
+		branch: ptree::Style::default(),
+		styled: ptree::print_config::StyleWhen::Never,
+		..ptree::PrintConfig::default()
+	};
+	for root in roots {
+		t.graph
+			.node_weight(root)
+			.ok_or(TournamentError::RoundNotFound(root))?;
+		ptree::write_tree_with(
+			&PrintTournament(t, root, config),
+			&mut *writer,
+			&tree_config,
+		)
+		.map_err(TournamentError::Io)?;
+	}
+	Ok(())
 }
@@ -1,8 +1,10 @@
 use petgraph::graph::NodeIndex;
+use rand::RngCore;
 use std::clone::Clone;
 use std::default::Default;
 use std::fmt;
 use std::fmt::{Debug, Display};
+use std::marker::PhantomData;
 use std::sync::{Arc, RwLock};
 
 /// Standard [`Result`](https://doc.rust-lang.org/std/result/) type alias for the library. Error type is [`TournamentError`](enum.TournamentError.html)
@@ -10,18 +12,37 @@ pub type Result<T> = std::result::Result<T, TournamentError>;
 
 /// Implement this trait to create a system for solving battles betweeen two structs.
 ///
+/// A tournament owns an instance of its battle system and calls it through `&mut self`, so a battle system can carry configuration or counters between rounds. Battle systems that implement `Default` can be used with constructors such as [`Tournament::new()`](struct.Tournament.html#method.new). Others are passed in with constructors such as [`Tournament::new_with_battle_system()`](struct.Tournament.html#method.new_with_battle_system).
+///
+/// Battle systems written before `BattleSystem` took `&mut self` and a random number generator can be kept unchanged by implementing [`StatelessBattleSystem`](trait.StatelessBattleSystem.html) instead, and using them through [`Stateless`](struct.Stateless.html).
+///
 /// # Example
 /// The larger number wins. Ties are resolved randomly.
 /// ```
-/// use crate::MyMetadata;
-///
-/// #[derive(Clone)]
+/// # use ultra_tournament::*;
+/// # use rand::{Rng, RngCore};
+/// # use std::sync::{Arc, RwLock};
+/// # #[derive(Debug, Clone, Default)]
+/// # struct MyMetadata;
+/// # impl MyMetadata {
+/// # 	fn new() -> Self {
+/// # 		Self
+/// # 	}
+/// # }
+/// # impl std::fmt::Display for MyMetadata {
+/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// # 		write!(f, "My Metadata")
+/// # 	}
+/// # }
+/// #[derive(Clone, Default)]
 /// struct U32BattleSystem;
 /// impl BattleSystem<u32, MyMetadata> for U32BattleSystem {
 ///
 /// 	fn battle(
+/// 		&mut self,
 /// 		a_arc: Arc<RwLock<u32>>,
 /// 		b_arc: Arc<RwLock<u32>>,
+/// 		_: &mut dyn RngCore,
 /// 	) -> BattleResult<MyMetadata> {
 /// 		use TournamentRoundResult::*;
 /// 		let a = a_arc.read().unwrap();
@@ -37,34 +58,47 @@ pub type Result<T> = std::result::Result<T, TournamentError>;
 /// 	}
 ///
 /// 	fn tiebreaker(
+/// 		&mut self,
 /// 		_: Arc<RwLock<u32>>,
 /// 		_: Arc<RwLock<u32>>,
-/// 	) -> (TournamentRoundResult, MyMetadata) {
-/// 		use rand::prelude::*;
+/// 		rng: &mut dyn RngCore,
+/// 	) -> TiebreakerResult<MyMetadata> {
 /// 		use TournamentRoundResult::*;
-/// 		(
-/// 			if random::<f32>() > 0.5 { A } else { B },
+/// 		Ok((
+/// 			if rng.gen::<f32>() > 0.5 { A } else { B },
 /// 			MyMetadata::new()
-/// 		)
+/// 		))
 /// 	}
 ///
 /// }
+/// # fn main() {}
 /// ```
 pub trait BattleSystem<
 	E: Debug + Display + Clone,
 	M: Debug + Display + Clone + Default,
->: Clone
+>
 {
 	/// - Resolves a round played between two entrants encapsulated in [`Arc`](https://doc.rust-lang.org/std/sync/struct.Arc.html)`<`[`RwLock`](https://doc.rust-lang.org/std/sync/struct.RwLock.html)`<E>>`s, allowing for mutation of entrants between rounds.
 	///
 	/// - Example funcationality: reduce a fighter's HP during a round, and retain the change in later rounds.
-	fn battle(a: Arc<RwLock<E>>, b: Arc<RwLock<E>>) -> BattleResult<M>;
+	///
+	/// - Any randomness should come from `rng`, the tournament's own random number generator, so that tournaments run with the same seed are reproducible.
+	///
+	/// - Return [`BattleResult::Failed`](enum.BattleResult.html#variant.Failed) if the round can't be solved, for example when an external simulator times out. The round is left incomplete, and the error is returned from the tournament's solving functions.
+	fn battle(
+		&mut self,
+		a: Arc<RwLock<E>>,
+		b: Arc<RwLock<E>>,
+		rng: &mut dyn RngCore,
+	) -> BattleResult<M>;
 
-	/// - In case `battle` returns a [`BattleResult::Tie`](enum.BattleResult.html#variant.Tie), run a tiebreaker that must return a successful result.
+	/// - In case `battle` returns a [`BattleResult::Tie`](enum.BattleResult.html#variant.Tie), run a tiebreaker that must either decide the round, or fail as per [`battle()`](#tymethod.battle).
 	fn tiebreaker(
+		&mut self,
 		a: Arc<RwLock<E>>,
 		b: Arc<RwLock<E>>,
-	) -> (TournamentRoundResult, M);
+		rng: &mut dyn RngCore,
+	) -> TiebreakerResult<M>;
 }
 
 /// Returned by the [`battle()`](trait.BattleSystem.html#tymethod.battle) function in implementations of [`BattleSystem`](trait.BattleSystem.html)
@@ -73,10 +107,120 @@ pub enum BattleResult<M: Debug + Display + Clone + Default> {
 	Solved(TournamentRoundResult, M),
 	/// A solve that resulted in a tie. When [`battle()`](trait.BattleSystem.html#tymethod.battle) returns this, [`tiebreaker()`](trait.BattleSystem.html#tymethod.tiebreaker) is run immediately after.
 	Tie,
+	/// The round couldn't be solved. The round is left incomplete, and the error is passed up as a [`TournamentError::BattleFailed`](enum.TournamentError.html#variant.BattleFailed).
+	Failed(BattleError),
+}
+
+/// Returned by the [`tiebreaker()`](trait.BattleSystem.html#tymethod.tiebreaker) function in implementations of [`BattleSystem`](trait.BattleSystem.html). Either the side that wins along with a piece of round metadata, or the error the tiebreaker failed with.
+pub type TiebreakerResult<M> =
+	std::result::Result<(TournamentRoundResult, M), BattleError>;
+
+/// A battle system with no state of its own, written against the original `BattleSystem` signatures: associated functions with no receiver and no injected random number generator, and a tiebreaker that can't fail. Wrap it in [`Stateless`](struct.Stateless.html) to use it as a [`BattleSystem`](trait.BattleSystem.html).
+///
+/// # Migrating
+/// A battle system written before `BattleSystem` took `&mut self` and a random number generator can be kept as it is:
+/// 1. Change `impl BattleSystem<E, M> for MySystem` to `impl StatelessBattleSystem<E, M> for MySystem`.
+/// 2. Name the tournament's battle system as `Stateless<MySystem>`, as in `Tournament<E, M, Stateless<MySystem>>`.
+///
+/// `MySystem` doesn't need to implement `Clone` or `Default`.
+///
+/// # Example
+/// ```
+/// # use ultra_tournament::*;
+/// # use std::sync::{Arc, RwLock};
+/// # #[derive(Debug, Clone, Default)]
+/// # struct MyMetadata;
+/// # impl std::fmt::Display for MyMetadata {
+/// # 	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+/// # 		write!(f, "My Metadata")
+/// # 	}
+/// # }
+/// struct U32BattleSystem;
+/// impl StatelessBattleSystem<u32, MyMetadata> for U32BattleSystem {
+/// 	fn battle(a: Arc<RwLock<u32>>, b: Arc<RwLock<u32>>) -> BattleResult<MyMetadata> {
+/// 		use TournamentRoundResult::*;
+/// 		if *a.read().unwrap() >= *b.read().unwrap() {
+/// 			BattleResult::Solved(A, MyMetadata)
+/// 		} else {
+/// 			BattleResult::Solved(B, MyMetadata)
+/// 		}
+/// 	}
+///
+/// 	fn tiebreaker(
+/// 		_: Arc<RwLock<u32>>,
+/// 		_: Arc<RwLock<u32>>,
+/// 	) -> (TournamentRoundResult, MyMetadata) {
+/// 		(TournamentRoundResult::A, MyMetadata)
+/// 	}
+/// }
+/// # fn main() {
+/// let mut t =
+/// 	Tournament::<u32, MyMetadata, Stateless<U32BattleSystem>>::new(vec![1, 2, 3, 4])
+/// 		.unwrap();
+/// t.solve().unwrap();
+/// assert_eq!(t.winner(*t.grand_finals()).unwrap(), Some(EntrantId(3)));
+/// # }
+/// ```
+pub trait StatelessBattleSystem<
+	E: Debug + Display + Clone,
+	M: Debug + Display + Clone + Default,
+>
+{
+	/// Resolves a round, as per [`BattleSystem::battle()`](trait.BattleSystem.html#tymethod.battle).
+	fn battle(a: Arc<RwLock<E>>, b: Arc<RwLock<E>>) -> BattleResult<M>;
+
+	/// In case `battle` returns a [`BattleResult::Tie`](enum.BattleResult.html#variant.Tie), run a tiebreaker that must return a successful result.
+	fn tiebreaker(
+		a: Arc<RwLock<E>>,
+		b: Arc<RwLock<E>>,
+	) -> (TournamentRoundResult, M);
+}
+
+/// Adapts a [`StatelessBattleSystem`](trait.StatelessBattleSystem.html) into a [`BattleSystem`](trait.BattleSystem.html). Holds no data, so it's `Clone`, `Default`, `Send` and `Sync` whatever `S` is.
+pub struct Stateless<S>(PhantomData<fn() -> S>);
+impl<S> Default for Stateless<S> {
+	fn default() -> Self {
+		Self(PhantomData)
+	}
+}
+impl<S> Clone for Stateless<S> {
+	fn clone(&self) -> Self {
+		Self::default()
+	}
+}
+impl<S> fmt::Debug for Stateless<S> {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		write!(f, "Stateless<{}>", std::any::type_name::<S>())
+	}
+}
+impl<
+		E: Debug + Display + Clone,
+		M: Debug + Display + Clone + Default,
+		S: StatelessBattleSystem<E, M>,
+	> BattleSystem<E, M> for Stateless<S>
+{
+	fn battle(
+		&mut self,
+		a: Arc<RwLock<E>>,
+		b: Arc<RwLock<E>>,
+		_: &mut dyn RngCore,
+	) -> BattleResult<M> {
+		S::battle(a, b)
+	}
+
+	fn tiebreaker(
+		&mut self,
+		a: Arc<RwLock<E>>,
+		b: Arc<RwLock<E>>,
+		_: &mut dyn RngCore,
+	) -> TiebreakerResult<M> {
+		Ok(S::tiebreaker(a, b))
+	}
 }
 
 /// The Id of an entrant in a [`Tournament`](struct.Tournament.html). A wrapper around a single `usize`. Implements [`Display`](https://doc.rust-lang.org/stable/rust-by-example/hello/print/print_display.html)
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EntrantId(pub usize);
 impl fmt::Display for EntrantId {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -85,18 +229,22 @@ impl fmt::Display for EntrantId {
 }
 
 /// The [node weight](https://docs.rs/petgraph/0.5.1/petgraph/graph/struct.Graph.html#method.node_weight) of a [`Tournament`](struct.Tournament.html)'s internal [graph](struct.Tournament.html#method.graph).
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TournamentNode<M: Debug + Display + Clone + Default> {
 	/// Represents the starting point of an entrant within the tournament bracket. Links to exactly one `Round` node.
 	Entrant(EntrantId),
 	/// Represents a round in the tournament. Links to two previous rounds or entrant nodes, and one future round node (except for the final round)
 	Round(TournamentRound<M>),
+	/// Represents the loser of the round it links to. Links to exactly one `Round` node, and is used to feed eliminated entrants into a losers bracket.
+	Loser,
 }
 impl<M: Debug + Display + Clone + Default> fmt::Display for TournamentNode<M> {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
 			Self::Entrant(eid) => write!(f, "{}", eid),
 			Self::Round(r) => write!(f, "{}", r),
+			Self::Loser => write!(f, "Loser"),
 		}
 	}
 }
@@ -139,10 +287,27 @@ impl<M: Debug + Display + Clone + Default> TournamentNode<M> {
 			None
 		}
 	}
+	/// Get how a node's round was decided. Returns `None` if the node is a `TournamentNode::Entrant`, or is incomplete.
+	pub fn decision(&self) -> Option<RoundDecision> {
+		if let Self::Round(round) = self {
+			round.decision()
+		} else {
+			None
+		}
+	}
+	/// Get the games of a node's series. Returns `None` if the node is a `TournamentNode::Entrant`, or is incomplete.
+	pub fn series(&self) -> Option<&Vec<SeriesGame<M>>> {
+		if let Self::Round(round) = self {
+			round.series()
+		} else {
+			None
+		}
+	}
 }
 
 /// A single round in a [`Tournament`](struct.Tournament.html)'s bracket.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TournamentRound<M: Debug + Display + Clone + Default> {
 	/// Represents a round that hasn't be solved / played out yet.
 	Incomplete,
@@ -152,38 +317,45 @@ pub enum TournamentRound<M: Debug + Display + Clone + Default> {
 		result: TournamentRoundResult,
 		/// Metadata associated with this round, as returned from [`BattleSystem::battle`](trait.BattleSystem.html#tymethod.battle) or [`BattleSystem::tiebreaker`](trait.BattleSystem.html#tymethod.tiebreaker)
 		metadata: M,
+		/// How the round was decided. Rounds saved before walkovers existed were always played.
+		#[cfg_attr(feature = "serde", serde(default))]
+		decision: RoundDecision,
+		/// Every game of the round, in the order they were played, if it was played as a [best-of-N series](struct.Tournament.html#method.set_best_of). Empty if the round was a single game, was reported, or was decided by walkover. `metadata` is that of the deciding game.
+		#[cfg_attr(feature = "serde", serde(default))]
+		series: Vec<SeriesGame<M>>,
 	},
 }
 impl<M: Debug + Display + Clone + Default> fmt::Display for TournamentRound<M> {
 	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
 		match self {
 			Self::Incomplete => write!(f, "Incomplete"),
-			Self::Complete { result, metadata } => {
-				write!(f, "{} --- {}", result, metadata)
-			}
+			Self::Complete {
+				result,
+				metadata,
+				decision: RoundDecision::Walkover,
+				..
+			} => write!(f, "{} by walkover --- {}", result, metadata),
+			Self::Complete {
+				result, metadata, ..
+			} => match self.score() {
+				Some((a, b)) => write!(f, "{} {}-{} --- {}", result, a, b, metadata),
+				None => write!(f, "{} --- {}", result, metadata),
+			},
 		}
 	}
 }
 impl<M: Debug + Display + Clone + Default> TournamentRound<M> {
 	/// Get the metadata of a round. Returns `None` if the round is incomplete.
 	pub fn metadata(&self) -> Option<&M> {
-		if let TournamentRound::<M>::Complete {
-			result: _,
-			metadata,
-		} = self
-		{
-			Some(&metadata)
+		if let TournamentRound::<M>::Complete { metadata, .. } = self {
+			Some(metadata)
 		} else {
 			None
 		}
 	}
 	/// Get a mutable reference to the metadata of a round. Returns `None` if the round is incomplete.
 	pub fn metadata_mut(&mut self) -> Option<&mut M> {
-		if let TournamentRound::<M>::Complete {
-			result: _,
-			metadata,
-		} = self
-		{
+		if let TournamentRound::<M>::Complete { metadata, .. } = self {
 			Some(metadata)
 		} else {
 			None
@@ -191,22 +363,65 @@ impl<M: Debug + Display + Clone + Default> TournamentRound<M> {
 	}
 	/// Get the result of a round. Returns `None` if the round is incomplete.
 	pub fn result(&self) -> Option<&TournamentRoundResult> {
-		if let TournamentRound::<M>::Complete {
-			result,
-			metadata: _,
-		} = self
-		{
+		if let TournamentRound::<M>::Complete { result, .. } = self {
 			Some(result)
 		} else {
 			None
 		}
 	}
+	/// Get how a round was decided. Returns `None` if the round is incomplete.
+	pub fn decision(&self) -> Option<RoundDecision> {
+		if let TournamentRound::<M>::Complete { decision, .. } = self {
+			Some(*decision)
+		} else {
+			None
+		}
+	}
+	/// Get the games of a round's series. Returns `None` if the round is incomplete.
+	pub fn series(&self) -> Option<&Vec<SeriesGame<M>>> {
+		if let TournamentRound::<M>::Complete { series, .. } = self {
+			Some(series)
+		} else {
+			None
+		}
+	}
+	/// Get the number of games won by sides `A` and `B` in a round's series. Returns `None` if the round is incomplete, or wasn't played as a series.
+	pub fn score(&self) -> Option<(usize, usize)> {
+		let series = self.series().filter(|series| !series.is_empty())?;
+		let wins_a = series
+			.iter()
+			.filter(|game| game.result == TournamentRoundResult::A)
+			.count();
+		Some((wins_a, series.len() - wins_a))
+	}
+}
+
+/// A single game of a round played as a [best-of-N series](struct.Tournament.html#method.set_best_of).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SeriesGame<M: Debug + Display + Clone + Default> {
+	/// The winner of the game.
+	pub result: TournamentRoundResult,
+	/// Metadata associated with the game, as returned from [`BattleSystem::battle`](trait.BattleSystem.html#tymethod.battle) or [`BattleSystem::tiebreaker`](trait.BattleSystem.html#tymethod.tiebreaker)
+	pub metadata: M,
+}
+
+/// How a [`TournamentRound`](enum.TournamentRound.html) was decided.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RoundDecision {
+	/// The round was played, either by the battle system or outside of it and [reported](struct.Tournament.html#method.report_result).
+	#[default]
+	Played,
+	/// The round was awarded to one side without being played, because the other side had [withdrawn](struct.Tournament.html#method.withdraw).
+	Walkover,
 }
 
 /// The [edge weight](https://docs.rs/petgraph/0.5.1/petgraph/graph/struct.Graph.html#method.edge_weight) of a [`Tournament`](struct.Tournament.html)'s internal [graph](struct.Tournament.html#method.graph).
 ///
 /// Convertible to [`TournamentRoundResult`](enum.TournamentRoundResult.html)
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TournamentEdge {
 	/// Represents a connection from one round to the next on size `A`.
 	A,
@@ -226,6 +441,7 @@ impl std::convert::From<TournamentRoundResult> for TournamentEdge {
 ///
 /// Convertible to [`TournamentEdge`](enum.TournamentEdge.html)
 #[derive(Debug, Clone, Copy, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TournamentRoundResult {
 	/// Represents the winner being on side `A`.
 	A,
@@ -249,19 +465,107 @@ impl fmt::Display for TournamentRoundResult {
 	}
 }
 
-/// Enum used for all errors in the crate.
-#[derive(Debug, Clone, Copy)]
+/// The error type a [`BattleSystem`](trait.BattleSystem.html) can fail with, carried by [`TournamentError::BattleFailed`](enum.TournamentError.html#variant.BattleFailed).
+pub type BattleError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Enum used for all errors in the crate. Implements `Display` and [`std::error::Error`](https://doc.rust-lang.org/std/error/trait.Error.html), so that it can be passed up with `?` alongside other errors.
+#[derive(Debug)]
 pub enum TournamentError {
 	/// Returned when a [`Tournament`](struct.Tournament.html)'s internal [graph](struct.Tournament.html#method.graph) doesn't contain a certain [`NodeIndex`](https://docs.rs/petgraph/0.5.1/petgraph/graph/struct.NodeIndex.html)
 	RoundNotFound(NodeIndex),
 	/// Returned when a [`Tournament`](struct.Tournament.html) doesn't contain an entrant of a certain [`EntrantId`](struct.EntrantId.html)
 	EntrantNotFound(EntrantId),
-	/// Returned when a [`Tournament`](struct.Tournament.html)'s internal [graph](struct.Tournament.html#method.graph) is somehow malformed. This can be caused by manipulating the graph's structure after the tournament is instantiated.
+	/// Returned when a node of a [`Tournament`](struct.Tournament.html)'s internal [graph](struct.Tournament.html#method.graph) was expected to be a [`TournamentNode::Round`](enum.TournamentNode.html#variant.Round), but isn't.
+	NotARound(NodeIndex),
+	/// Returned by [`Tournament::report_result()`](struct.Tournament.html#method.report_result) when either side of the round hasn't been decided yet.
+	RoundNotReady(NodeIndex),
+	/// Returned by [`Tournament::report_result()`](struct.Tournament.html#method.report_result) when the round already has a result.
+	RoundAlreadyComplete(NodeIndex),
+	/// Returned when a league format, such as a [`RoundRobin`](struct.RoundRobin.html), doesn't contain a match of a certain index.
+	MatchNotFound(usize),
+	/// Returned when a node of a [`Tournament`](struct.Tournament.html)'s internal [graph](struct.Tournament.html#method.graph) isn't linked to the nodes around it as expected, such as a round without exactly one [`A`](enum.TournamentEdge.html#variant.A) and one [`B`](enum.TournamentEdge.html#variant.B) child. This can be caused by manipulating the graph's structure after the tournament is instantiated.
+	MalformedRound(NodeIndex),
+	/// Returned when a tournament is somehow malformed as a whole, and no single round is to blame.
 	MalformedBracket,
+	/// Returned when the lock around an entrant is poisoned, because a thread panicked while holding it.
+	EntrantLockPoisoned(EntrantId),
+	/// Returned when the battle system fails to solve a round, carrying the round and the battle system's error.
+	BattleFailed(NodeIndex, BattleError),
+	/// Returned when the battle system fails to solve a match of a league format, such as a [`RoundRobin`](struct.RoundRobin.html), carrying the index of the match and the battle system's error.
+	MatchFailed(usize, BattleError),
 	/// Returned when attempting to create a [`Tournament`](struct.Tournament.html) with zero entrants.
 	NeedsAtLeastOneEntrant,
-	/// Catchall other error.
-	Other(&'static str),
-	/// Returned by [`print_tournament`](fn.print_tournament.html) when some error prevents it from formatting the tree.
-	PrintFailure,
+	/// Returned when attempting to create a [`DoubleEliminationTournament`](struct.DoubleEliminationTournament.html) with fewer than two entrants.
+	NeedsAtLeastTwoEntrants,
+	/// Returned by [`Tournament::with_third_place_playoff()`](struct.Tournament.html#method.with_third_place_playoff) when the bracket doesn't have two semifinals, which takes at least four entrants.
+	NeedsAtLeastFourEntrants,
+	/// Returned when attempting to create a [`RoundRobin`](struct.RoundRobin.html) with zero legs.
+	NeedsAtLeastOneLeg,
+	/// Returned when attempting to create a [`Swiss`](struct.Swiss.html) tournament with zero rounds.
+	NeedsAtLeastOneRound,
+	/// Returned when attempting to run a [`Simulation`](struct.Simulation.html) with zero runs.
+	NeedsAtLeastOneRun,
+	/// Returned by [`Tournament::new_seeded()`](struct.Tournament.html#method.new_seeded) when a [`Seeding::Custom`](enum.Seeding.html#variant.Custom) order doesn't contain every entrant exactly once.
+	InvalidSeeding,
+	/// Returned by [`Tournament::set_best_of()`](struct.Tournament.html#method.set_best_of) and similar when the length of a series isn't odd, carrying the length.
+	InvalidBestOf(u32),
+	/// Returned by [`print_tournament()`](fn.print_tournament.html) and [`write_tournament()`](fn.write_tournament.html) when writing the tree fails, carrying the underlying error.
+	Io(std::io::Error),
+}
+impl fmt::Display for TournamentError {
+	fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+		match self {
+			Self::RoundNotFound(id) => write!(f, "round {} not found", id.index()),
+			Self::EntrantNotFound(eid) => write!(f, "{} not found", eid),
+			Self::NotARound(id) => write!(f, "node {} is not a round", id.index()),
+			Self::RoundNotReady(id) => write!(
+				f,
+				"round {} is not ready, as both sides haven't been decided",
+				id.index()
+			),
+			Self::RoundAlreadyComplete(id) => {
+				write!(f, "round {} is already complete", id.index())
+			}
+			Self::MatchNotFound(i) => write!(f, "match {} not found", i),
+			Self::MalformedRound(id) => {
+				write!(f, "node {} is malformed", id.index())
+			}
+			Self::MalformedBracket => write!(f, "malformed bracket"),
+			Self::EntrantLockPoisoned(eid) => {
+				write!(f, "the lock around {} is poisoned", eid)
+			}
+			Self::BattleFailed(id, e) => {
+				write!(f, "battle system failed in round {}: {}", id.index(), e)
+			}
+			Self::MatchFailed(i, e) => {
+				write!(f, "battle system failed in match {}: {}", i, e)
+			}
+			Self::NeedsAtLeastOneEntrant => write!(f, "needs at least one entrant"),
+			Self::NeedsAtLeastTwoEntrants => {
+				write!(f, "needs at least two entrants")
+			}
+			Self::NeedsAtLeastFourEntrants => {
+				write!(f, "needs at least four entrants")
+			}
+			Self::NeedsAtLeastOneLeg => write!(f, "needs at least one leg"),
+			Self::NeedsAtLeastOneRound => write!(f, "needs at least one round"),
+			Self::NeedsAtLeastOneRun => write!(f, "needs at least one run"),
+			Self::InvalidSeeding => {
+				write!(f, "custom seeding must contain every entrant exactly once")
+			}
+			Self::InvalidBestOf(n) => {
+				write!(f, "a series must be an odd number of games, not {}", n)
+			}
+			Self::Io(e) => write!(f, "failed to write tournament: {}", e),
+		}
+	}
+}
+impl std::error::Error for TournamentError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Self::BattleFailed(_, e) | Self::MatchFailed(_, e) => Some(e.as_ref()),
+			Self::Io(e) => Some(e),
+			_ => None,
+		}
+	}
 }